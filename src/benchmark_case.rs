@@ -0,0 +1,87 @@
+use crate::{Distance, DistanceUnit};
+use geo_types::Point;
+
+/// A representative measurement input, to compare the ruler's results with a
+/// reference implementation such as cheap-ruler-js or a geodesic library
+#[derive(Debug, PartialEq, Clone)]
+pub struct BenchmarkCase {
+    name: String,
+    latitude: f64,
+    origin: Point<f64>,
+    distance: Distance,
+    bearing: f64,
+}
+
+impl BenchmarkCase {
+    /// Name describing the case
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Latitude to create the ruler for
+    pub fn latitude(&self) -> f64 {
+        self.latitude
+    }
+
+    /// Origin point, at the ruler's latitude
+    pub fn origin(&self) -> Point<f64> {
+        self.origin
+    }
+
+    /// Distance from the origin to measure or project
+    pub fn distance(&self) -> Distance {
+        self.distance
+    }
+
+    /// Bearing from the origin, in degrees
+    pub fn bearing(&self) -> f64 {
+        self.bearing
+    }
+}
+
+/// Returns a fixed set of representative inputs covering the latitudes,
+/// distances and bearings the ruler is used with, from the equator to high
+/// latitudes and from meters to a hundred kilometers. Downstream users can
+/// feed them to the ruler and to a reference implementation to build their
+/// own accuracy reports.
+///
+/// # Examples
+///
+/// ```
+/// use cheap_ruler::{benchmark_suite, CheapRuler};
+/// for case in benchmark_suite() {
+///     let ruler = CheapRuler::new(case.latitude(), case.distance().unit());
+///     let destination = ruler.destination(
+///         &case.origin(),
+///         case.distance().value(),
+///         case.bearing(),
+///     );
+/// }
+/// ```
+pub fn benchmark_suite() -> Vec<BenchmarkCase> {
+    let latitudes = [0.0, 32.8351, 45.0, 60.0, 75.0];
+    let distances = [0.01, 1.0, 10.0, 100.0];
+    let bearings = [0.0, 45.0, 90.0, 135.0, -90.0];
+
+    let mut cases = vec![];
+    for &latitude in latitudes.iter() {
+        for &distance in distances.iter() {
+            for &bearing in bearings.iter() {
+                cases.push(BenchmarkCase {
+                    name: format!(
+                        "latitude {}, {} km, bearing {}",
+                        latitude, distance, bearing
+                    ),
+                    latitude,
+                    origin: Point::new(14.8901816, latitude),
+                    distance: Distance::new(
+                        distance,
+                        DistanceUnit::Kilometers,
+                    ),
+                    bearing,
+                });
+            }
+        }
+    }
+    cases
+}
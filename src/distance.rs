@@ -0,0 +1,72 @@
+use crate::DistanceUnit;
+use std::cmp::Ordering;
+use std::ops::{Add, Sub};
+
+/// A distance tagged with the unit it is expressed in, preventing distances
+/// in different units from being mixed up
+///
+/// Adding or subtracting distances in different units panics, and comparing
+/// them yields no ordering. Use `to_unit` to convert between units first.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Distance {
+    value: f64,
+    unit: DistanceUnit,
+}
+
+impl Distance {
+    pub fn new(value: f64, unit: DistanceUnit) -> Self {
+        Self { value, unit }
+    }
+
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    pub fn unit(&self) -> DistanceUnit {
+        self.unit
+    }
+
+    /// Converts the distance to the given unit
+    ///
+    /// # Arguments
+    ///
+    /// * `unit` - Unit to express the distance in
+    pub fn to_unit(&self, unit: DistanceUnit) -> Self {
+        let km = self.value / self.unit.conversion_factor_kilometers();
+        Self::new(km * unit.conversion_factor_kilometers(), unit)
+    }
+
+    fn assert_same_unit(&self, other: &Self) {
+        assert_eq!(
+            self.unit, other.unit,
+            "Distances expressed in different units"
+        );
+    }
+}
+
+impl Add for Distance {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        self.assert_same_unit(&other);
+        Self::new(self.value + other.value, self.unit)
+    }
+}
+
+impl Sub for Distance {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self.assert_same_unit(&other);
+        Self::new(self.value - other.value, self.unit)
+    }
+}
+
+impl PartialOrd for Distance {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self.unit != other.unit {
+            return None;
+        }
+        self.value.partial_cmp(&other.value)
+    }
+}
@@ -1,4 +1,8 @@
-/// Defines common units of distance that can be used
+/// Defines common units of distance that can be used, as well as custom ones
+///
+/// Because `Custom` holds an `f64`, units can only be compared with
+/// `PartialEq`, which compares custom scales exactly, and don't implement
+/// `Eq` or `Hash`.
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub enum DistanceUnit {
     Kilometers,
@@ -8,6 +12,9 @@ pub enum DistanceUnit {
     Yards,
     Feet,
     Inches,
+    /// A unit of the given length in kilometers, e.g. `0.08` for 80 meter
+    /// city blocks
+    Custom { km_per_unit: f64 },
 }
 
 impl DistanceUnit {
@@ -21,6 +28,7 @@ impl DistanceUnit {
             DistanceUnit::Yards => 1000.0 / 0.9144,
             DistanceUnit::Feet => 1000.0 / 0.3048,
             DistanceUnit::Inches => 1000.0 / 0.0254,
+            DistanceUnit::Custom { km_per_unit } => 1.0 / km_per_unit,
         }
     }
 }
@@ -0,0 +1,55 @@
+use crate::CheapRuler;
+use geo_types::Point;
+
+/// Running total of the length of a line whose points arrive one at a time,
+/// without storing the line itself
+#[derive(Debug, PartialEq, Clone)]
+pub struct LengthAccumulator {
+    ruler: CheapRuler,
+    previous: Option<Point<f64>>,
+    total: f64,
+}
+
+impl LengthAccumulator {
+    /// Creates an empty accumulator measuring with the given ruler
+    ///
+    /// # Arguments
+    ///
+    /// * `ruler` - Ruler used to measure the distance between pushed points
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheap_ruler::{CheapRuler, DistanceUnit, LengthAccumulator};
+    /// let cr = CheapRuler::new(44.7192003, DistanceUnit::Meters);
+    /// let mut acc = LengthAccumulator::new(cr);
+    /// acc.push((14.8901816, 44.7209699).into());
+    /// acc.push((14.8905188, 44.7209699).into());
+    /// assert!(acc.total() < 38.0);
+    /// ```
+    pub fn new(ruler: CheapRuler) -> Self {
+        Self {
+            ruler,
+            previous: None,
+            total: 0.0,
+        }
+    }
+
+    /// Adds the next point of the line, increasing the total by its distance
+    /// from the previously pushed point
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - Next point
+    pub fn push(&mut self, p: Point<f64>) {
+        if let Some(previous) = self.previous {
+            self.total += self.ruler.distance(&previous, &p);
+        }
+        self.previous = Some(p);
+    }
+
+    /// Gets the length of the line pushed so far
+    pub fn total(&self) -> f64 {
+        self.total
+    }
+}
@@ -9,23 +9,47 @@
 //! Note: WGS84 ellipsoid is used instead of the Clarke 1866 parameters used by
 //! the FCC formulas. See cheap-ruler-cpp#13 for more information.
 
+#![allow(deprecated)]
 #[macro_use]
 extern crate geo_types;
 
 use float_extras::f64::remainder;
-use geo_types::{Coordinate, LineString, Point, Polygon};
+use geo_types::{
+    Coordinate,
+    Geometry,
+    Line,
+    LineString,
+    MultiLineString,
+    MultiPolygon,
+    Point,
+    Polygon,
+};
+use std::cmp::Ordering;
 use std::f64;
 use std::iter;
 use std::mem;
 
+pub use benchmark_case::{benchmark_suite, BenchmarkCase};
+pub use distance::Distance;
 pub use distance_unit::DistanceUnit;
+pub use length_accumulator::LengthAccumulator;
+pub use local_frame::LocalFrame;
+pub use matcher::Matcher;
+pub use measurement::Measurement;
+pub use orientation::Orientation;
 pub use point_on_line::PointOnLine;
 pub use rect::Rect;
+pub use side::Side;
 
-const RE: f64 = 6378.137; // equatorial radius in km
-const FE: f64 = 1.0 / 298.257223563; // flattening
-const E2: f64 = FE * (2.0 - FE);
-const RAD: f64 = f64::consts::PI / 180.0;
+/// Equatorial radius of the WGS84 ellipsoid in kilometers
+pub const WGS84_EQUATORIAL_RADIUS: f64 = 6378.137;
+/// Flattening of the WGS84 ellipsoid
+pub const WGS84_FLATTENING: f64 = 1.0 / 298.257223563;
+
+const RM: f64 = 6371.0088; // mean radius of the WGS84 ellipsoid in km
+
+/// Number of radians in a degree, used for all degree/radian conversions
+pub const RAD: f64 = f64::consts::PI / 180.0;
 
 /// A collection of very fast approximations to common geodesic measurements.
 /// Useful for performance-sensitive code that measures things on a city scale.
@@ -36,20 +60,49 @@ pub struct CheapRuler {
     ky: f64,
     dkx: f64,
     dky: f64,
+    latitude: f64,
     distance_unit: DistanceUnit,
 }
 
 impl CheapRuler {
     pub fn new(latitude: f64, distance_unit: DistanceUnit) -> Self {
-        // Curvature formulas from https://en.wikipedia.org/wiki/Earth_radius#Meridional
-        let coslat = (latitude * RAD).cos();
-        let w2 = 1.0 / (1.0 - E2 * (1.0 - coslat * coslat));
-        let w = w2.sqrt();
-
-        // multipliers for converting longitude and latitude degrees into distance
-        let dkx = w * coslat; // based on normal radius of curvature
-        let dky = w * w2 * (1.0 - E2); // based on meridonal radius of curvature
+        Self::with_ellipsoid(
+            latitude,
+            distance_unit,
+            WGS84_EQUATORIAL_RADIUS,
+            WGS84_FLATTENING,
+        )
+    }
 
+    /// Creates a ruler object using the given ellipsoid instead of WGS84, e.g.
+    /// GRS80 or a custom datum
+    ///
+    /// # Arguments
+    ///
+    /// * `latitude` - Latitude to create the ruler for
+    /// * `distance_unit` - Unit to express distances in
+    /// * `equatorial_radius` - Equatorial radius of the ellipsoid in kilometers
+    /// * `flattening` - Flattening of the ellipsoid
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheap_ruler::{CheapRuler, DistanceUnit};
+    /// let grs80 = CheapRuler::with_ellipsoid(
+    ///     44.7192003,
+    ///     DistanceUnit::Meters,
+    ///     6378.137,
+    ///     1.0 / 298.257222101,
+    /// );
+    /// ```
+    pub fn with_ellipsoid(
+        latitude: f64,
+        distance_unit: DistanceUnit,
+        equatorial_radius: f64,
+        flattening: f64,
+    ) -> Self {
+        let (dkx, dky) =
+            curvature_multipliers(latitude, equatorial_radius, flattening);
         let (kx, ky) = calculate_multipliers(distance_unit, dkx, dky);
 
         Self {
@@ -57,6 +110,7 @@ impl CheapRuler {
             ky,
             dkx,
             dky,
+            latitude,
             distance_unit,
         }
     }
@@ -64,6 +118,10 @@ impl CheapRuler {
     /// Creates a ruler object from tile coordinates (y and z). Convenient in
     /// tile-reduce scripts
     ///
+    /// The tile x coordinate is not needed: a ruler only depends on the
+    /// latitude, which is the same for all the tiles of a row. The ruler is
+    /// created for the latitude of the tile center (see `tile_center`).
+    ///
     /// # Arguments
     ///
     /// * `y` - y
@@ -79,9 +137,7 @@ impl CheapRuler {
     pub fn from_tile(y: u32, z: u32, distance_unit: DistanceUnit) -> Self {
         assert!(z < 32);
 
-        let n = f64::consts::PI
-            * (1.0 - 2.0 * (y as f64 + 0.5) / ((1u32 << z) as f64));
-        let latitude = n.sinh().atan() / RAD;
+        let latitude = tile_lat(y as f64 + 0.5, z);
 
         Self::new(latitude, distance_unit)
     }
@@ -111,9 +167,57 @@ impl CheapRuler {
             ky,
             dkx: self.dkx,
             dky: self.dky,
+            latitude: self.latitude,
         }
     }
 
+    /// Creates a ruler object from its latitude and distance unit, as returned
+    /// by `to_parts`
+    ///
+    /// # Arguments
+    ///
+    /// * `parts` - Latitude and distance unit of the ruler
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheap_ruler::{CheapRuler, DistanceUnit};
+    /// let cr = CheapRuler::new(44.7192003, DistanceUnit::Meters);
+    /// assert_eq!(cr, CheapRuler::from_parts(cr.to_parts()));
+    /// ```
+    pub fn from_parts(parts: (f64, DistanceUnit)) -> Self {
+        let (latitude, distance_unit) = parts;
+        Self::new(latitude, distance_unit)
+    }
+
+    /// Gets the latitude and distance unit that define the ruler, from which
+    /// it can be recreated with `from_parts`. Rulers created with a custom
+    /// ellipsoid are recreated using WGS84.
+    pub fn to_parts(&self) -> (f64, DistanceUnit) {
+        (self.latitude, self.distance_unit)
+    }
+
+    /// Gets the latitude that the ruler was instantiated with
+    pub fn latitude(&self) -> f64 {
+        self.latitude
+    }
+
+    /// Returns true if both rulers use the same distance unit and all of their
+    /// multipliers are within `epsilon` of each other, so that rulers created
+    /// for slightly different latitudes can be considered interchangeable
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Ruler to compare with
+    /// * `epsilon` - Maximum absolute difference between multipliers
+    pub fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.distance_unit == other.distance_unit
+            && (self.kx - other.kx).abs() <= epsilon
+            && (self.ky - other.ky).abs() <= epsilon
+            && (self.dkx - other.dkx).abs() <= epsilon
+            && (self.dky - other.dky).abs() <= epsilon
+    }
+
     /// Gets the distance unit that the ruler was instantiated with
     pub fn distance_unit(&self) -> DistanceUnit {
         self.distance_unit
@@ -154,6 +258,175 @@ impl CheapRuler {
         self.square_distance(a, b).sqrt()
     }
 
+    /// Returns the average speed between two fixes taken the given number of
+    /// seconds apart, in ruler units per second (e.g. meters per second for a
+    /// ruler in meters)
+    ///
+    /// Returns 0 if no time elapsed between the fixes (or a negative time is
+    /// given), such as for two fixes with the same timestamp.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - First point
+    /// * `b` - Second point
+    /// * `seconds` - Time elapsed between the two fixes
+    pub fn speed(&self, a: &Point<f64>, b: &Point<f64>, seconds: f64) -> f64 {
+        if seconds <= 0.0 {
+            return 0.0;
+        }
+        self.distance(a, b) / seconds
+    }
+
+    /// Returns the time of closest approach of two points moving in straight
+    /// lines at constant velocities, and the distance between them at that
+    /// time, as a tuple of the form (time, distance)
+    ///
+    /// Velocities are (east, north) components in ruler units per second, and
+    /// the time is in seconds from now. If the points are moving apart, or not
+    /// moving relative to each other, the time is 0 and the distance is their
+    /// current distance.
+    ///
+    /// # Arguments
+    ///
+    /// * `p1` - Current position of the first point
+    /// * `v1` - Velocity of the first point
+    /// * `p2` - Current position of the second point
+    /// * `v2` - Velocity of the second point
+    pub fn closest_approach(
+        &self,
+        p1: &Point<f64>,
+        v1: (f64, f64),
+        p2: &Point<f64>,
+        v2: (f64, f64),
+    ) -> (f64, f64) {
+        let (x, y) = self.to_local(&p1.0, p2.0);
+        let (vx, vy) = (v2.0 - v1.0, v2.1 - v1.1);
+        let v2 = vx * vx + vy * vy;
+
+        let time = if v2 == 0.0 {
+            0.0
+        } else {
+            (-(x * vx + y * vy) / v2).max(0.0)
+        };
+        (time, (x + vx * time).hypot(y + vy * time))
+    }
+
+    /// Returns whether two geographical points are within the given distance
+    /// of each other, comparing squared distances to avoid a square root
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - First point
+    /// * `b` - Second point
+    /// * `radius` - Maximum distance between the points
+    pub fn within(&self, a: &Point<f64>, b: &Point<f64>, radius: f64) -> bool {
+        self.square_distance(a, b) <= radius * radius
+    }
+
+    /// Returns the given distance unchanged, checking in debug builds that it
+    /// is plausible in the ruler's unit, i.e. no longer than half the WGS84
+    /// equatorial circumference. This catches distances expressed in another
+    /// unit than the ruler's, like meters given to a ruler in kilometers. The
+    /// check is compiled out of release builds.
+    ///
+    /// # Arguments
+    ///
+    /// * `dist` - Distance expected to be in the ruler's unit
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheap_ruler::{CheapRuler, DistanceUnit};
+    /// let cr = CheapRuler::new(44.7192003, DistanceUnit::Kilometers);
+    /// let dist = cr.with_unit_check(cr.distance(
+    ///   &(14.8901816, 44.7209699).into(),
+    ///   &(14.8905188, 44.7209699).into()
+    /// ));
+    /// ```
+    pub fn with_unit_check(&self, dist: f64) -> f64 {
+        debug_assert!(
+            dist.abs()
+                <= f64::consts::PI
+                    * WGS84_EQUATORIAL_RADIUS
+                    * self.distance_unit.conversion_factor_kilometers(),
+            "Distance {} is too large to be in {:?}",
+            dist,
+            self.distance_unit
+        );
+        dist
+    }
+
+    /// Returns the distances between all pairs of the given points, as a
+    /// row-major N×N matrix where the distance between points `i` and `j` is
+    /// at index `i * N + j`. Each pair is only measured once, the matrix being
+    /// symmetric with a zero diagonal.
+    ///
+    /// The matrix grows quadratically with the number of points, so this is
+    /// intended for small sets of up to a few hundred points.
+    ///
+    /// # Arguments
+    ///
+    /// * `points` - Points to measure the distances between
+    pub fn distance_matrix(&self, points: &[Point<f64>]) -> Vec<f64> {
+        let n = points.len();
+        let mut matrix = vec![0.0; n * n];
+        for i in 0..n {
+            for j in i + 1..n {
+                let d = self.square_distance(&points[i], &points[j]).sqrt();
+                matrix[i * n + j] = d;
+                matrix[j * n + i] = d;
+            }
+        }
+        matrix
+    }
+
+    /// Calculates the approximate distance between two geographical points
+    /// given as `(longitude, latitude)` tuples
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - First point
+    /// * `b` - Second point
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheap_ruler::{CheapRuler, DistanceUnit};
+    /// let cr = CheapRuler::new(44.7192003, DistanceUnit::Meters);
+    /// let dist = cr.distance_xy(
+    ///   (14.8901816, 44.7209699),
+    ///   (14.8905188, 44.7209699)
+    /// );
+    /// assert!(dist < 38.0);
+    /// ```
+    pub fn distance_xy(&self, a: (f64, f64), b: (f64, f64)) -> f64 {
+        self.distance(&a.into(), &b.into())
+    }
+
+    /// Calculates the approximate distance between two geographical points,
+    /// tagged with the ruler's distance unit
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - First point
+    /// * `b` - Second point
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheap_ruler::{CheapRuler, DistanceUnit};
+    /// let cr = CheapRuler::new(44.7192003, DistanceUnit::Meters);
+    /// let dist = cr.distance_typed(
+    ///   &(14.8901816, 44.7209699).into(),
+    ///   &(14.8905188, 44.7209699).into()
+    /// );
+    /// assert_eq!(dist.unit(), DistanceUnit::Meters);
+    /// assert!(dist.value() < 38.0);
+    /// ```
+    pub fn distance_typed(&self, a: &Point<f64>, b: &Point<f64>) -> Distance {
+        Distance::new(self.distance(a, b), self.distance_unit)
+    }
+
     /// Returns the bearing between two points in angles
     ///
     /// # Arguments
@@ -179,6 +452,103 @@ impl CheapRuler {
         dx.atan2(dy) / RAD
     }
 
+    /// Returns the compass bearing from one point to another, in degrees
+    /// clockwise from north in the range 0 to 360, unlike `bearing` which
+    /// returns signed degrees from -180 to 180
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - Starting point
+    /// * `to` - Destination point
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheap_ruler::{CheapRuler, DistanceUnit};
+    /// let cr = CheapRuler::new(44.7192003, DistanceUnit::Meters);
+    /// let bearing = cr.bearing_to(
+    ///   &(14.8905188, 44.7209699).into(),
+    ///   &(14.8901816, 44.7209699).into()
+    /// );
+    /// assert_eq!(bearing, 270.0);
+    /// ```
+    pub fn bearing_to(&self, from: &Point<f64>, to: &Point<f64>) -> f64 {
+        let bearing = self.bearing(from, to);
+        if bearing < 0.0 {
+            // tiny negative bearings would otherwise round to 360
+            (bearing + 360.0) % 360.0
+        } else {
+            bearing
+        }
+    }
+
+    /// Returns the bearing from the start to the end of a line segment, the
+    /// same as `bearing` between its endpoints
+    ///
+    /// # Arguments
+    ///
+    /// * `seg` - Line segment
+    pub fn line_bearing(&self, seg: &Line<f64>) -> f64 {
+        self.bearing(&seg.start_point(), &seg.end_point())
+    }
+
+    /// Returns the length of a line segment, the same as `distance` between
+    /// its endpoints
+    ///
+    /// # Arguments
+    ///
+    /// * `seg` - Line segment
+    pub fn line_length(&self, seg: &Line<f64>) -> f64 {
+        self.distance(&seg.start_point(), &seg.end_point())
+    }
+
+    /// Returns the opposite of the given bearing, in the same -180 to 180 range
+    /// as returned by `bearing`
+    ///
+    /// # Arguments
+    ///
+    /// * `bearing` - bearing
+    pub fn reverse_bearing(&self, bearing: f64) -> f64 {
+        remainder(bearing + 180.0, 360.0)
+    }
+
+    /// Returns the reflection of a point through the origin point, i.e. the
+    /// point at the same distance from the origin in the opposite direction
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - Point to reflect through
+    /// * `p` - Point to reflect
+    pub fn mirror_point(
+        &self,
+        origin: &Point<f64>,
+        p: &Point<f64>,
+    ) -> Point<f64> {
+        let dx = long_diff(p.lng(), origin.lng()) * self.kx;
+        let dy = (p.lat() - origin.lat()) * self.ky;
+        self.offset(origin, -dx, -dy)
+    }
+
+    /// Returns the angle between two directed segments, i.e. the difference of
+    /// their bearings (see `bearing_difference`) in the range -180 to 180. The
+    /// segments don't need to share a vertex.
+    ///
+    /// # Arguments
+    ///
+    /// * `a1` - Start point of the first segment
+    /// * `a2` - End point of the first segment
+    /// * `b1` - Start point of the second segment
+    /// * `b2` - End point of the second segment
+    pub fn segment_angle(
+        &self,
+        a1: &Point<f64>,
+        a2: &Point<f64>,
+        b1: &Point<f64>,
+        b2: &Point<f64>,
+    ) -> f64 {
+        bearing_difference(self.bearing(a1, a2), self.bearing(b1, b2))
+    }
+
     /// Returns a new point given distance and bearing from the starting point
     ///
     /// # Arguments
@@ -207,8 +577,186 @@ impl CheapRuler {
         dist: f64,
         bearing: f64,
     ) -> Point<f64> {
-        let a = bearing * RAD;
-        self.offset(origin, a.sin() * dist, a.cos() * dist)
+        self.destination_rad(origin, dist, bearing * RAD)
+    }
+
+    /// Returns a new point given distance and bearing from the starting point,
+    /// like `destination`, but with the bearing given in radians instead of
+    /// degrees
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - origin point
+    /// * `dist` - distance
+    /// * `bearing_rad` - bearing in radians
+    pub fn destination_rad(
+        &self,
+        origin: &Point<f64>,
+        dist: f64,
+        bearing_rad: f64,
+    ) -> Point<f64> {
+        let (sin, cos) = bearing_rad.sin_cos();
+        self.offset(origin, sin * dist, cos * dist)
+    }
+
+    /// Returns a new point given distance and bearing from the starting point,
+    /// like `destination`, but following the great circle on a sphere with the
+    /// mean WGS84 radius instead of the ruler's flat approximation. This is
+    /// slower, but stays accurate over long distances.
+    ///
+    /// Close to the ruler's latitude, both methods agree within a fraction of
+    /// a percent of the distance, the difference between the sphere and the
+    /// ellipsoid (see `relative_error_at`).
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - origin point
+    /// * `dist` - distance
+    /// * `bearing` - bearing in degrees
+    pub fn great_circle_destination(
+        &self,
+        origin: &Point<f64>,
+        dist: f64,
+        bearing: f64,
+    ) -> Point<f64> {
+        let km = dist / self.distance_unit.conversion_factor_kilometers();
+        let delta = km / RM;
+        let theta = bearing * RAD;
+        let phi1 = origin.lat() * RAD;
+        let lambda1 = origin.lng() * RAD;
+
+        let phi2 = (phi1.sin() * delta.cos()
+            + phi1.cos() * delta.sin() * theta.cos())
+        .asin();
+        let lambda2 = lambda1
+            + (theta.sin() * delta.sin() * phi1.cos())
+                .atan2(delta.cos() - phi1.sin() * phi2.sin());
+
+        point!(x: remainder(lambda2 / RAD, 360.0), y: phi2 / RAD)
+    }
+
+    /// Computes the destination from the origin given distance and bearing,
+    /// then measures the distance and bearing back from the origin to it, and
+    /// returns the absolute residual errors as a tuple of the form
+    /// (distance error, bearing error in degrees). They show how consistent
+    /// `destination`, `distance` and `bearing` are with each other.
+    ///
+    /// The bearing error is meaningless for a distance of 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - origin point
+    /// * `dist` - distance
+    /// * `bearing` - bearing in degrees
+    pub fn roundtrip_residual(
+        &self,
+        origin: &Point<f64>,
+        dist: f64,
+        bearing: f64,
+    ) -> (f64, f64) {
+        let destination = self.destination(origin, dist, bearing);
+        let measured_dist = self.distance(origin, &destination);
+        let measured_bearing = self.bearing(origin, &destination);
+        (
+            (measured_dist - dist).abs(),
+            bearing_difference(bearing, measured_bearing).abs(),
+        )
+    }
+
+    /// Returns a closed polygon whose vertices all lie at the given distance
+    /// from the origin, at evenly spaced bearings starting north
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - origin point
+    /// * `dist` - distance of the ring's vertices from the origin
+    /// * `steps` - number of distinct vertices, at least 3
+    ///
+    /// # Panics
+    ///
+    /// Panics if `steps` is less than 3.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheap_ruler::{CheapRuler, DistanceUnit};
+    /// let cr = CheapRuler::new(44.7192003, DistanceUnit::Meters);
+    /// let origin = (14.8901816, 44.7209699).into();
+    /// let ring = cr.equidistant_ring(&origin, 500.0, 64);
+    /// assert_eq!(ring.exterior().num_coords(), 65);
+    /// ```
+    pub fn equidistant_ring(
+        &self,
+        origin: &Point<f64>,
+        dist: f64,
+        steps: usize,
+    ) -> Polygon<f64> {
+        assert!(steps >= 3, "At least 3 steps expected");
+
+        let ring = (0..steps)
+            .map(|i| {
+                let bearing = 360.0 * i as f64 / steps as f64;
+                self.destination(origin, dist, bearing)
+            })
+            .collect::<Vec<Point<f64>>>();
+
+        Polygon::new(ring.into(), vec![])
+    }
+
+    /// Returns a new point moved from `from` towards `to` by at most the given
+    /// step distance, stopping exactly at `to` when it is closer than the step
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - starting point
+    /// * `to` - target point
+    /// * `step` - maximum distance to move
+    pub fn move_towards(
+        &self,
+        from: &Point<f64>,
+        to: &Point<f64>,
+        step: f64,
+    ) -> Point<f64> {
+        let dist = self.distance(from, to);
+        if dist <= step {
+            *to
+        } else {
+            interpolate(from, to, step / dist)
+        }
+    }
+
+    /// Returns a copy of the point with its longitude and latitude rounded to
+    /// the given number of decimal places, to reduce the size of serialized
+    /// output. Six decimals are about 0.11 meters, plenty at city scale.
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - point
+    /// * `decimals` - number of decimal places to keep
+    pub fn round_to(&self, p: &Point<f64>, decimals: u32) -> Point<f64> {
+        let factor = 10f64.powi(decimals as i32);
+        point!(
+            x: (p.lng() * factor).round() / factor,
+            y: (p.lat() * factor).round() / factor
+        )
+    }
+
+    /// Returns a copy of the line with the longitude and latitude of all its
+    /// points rounded to the given number of decimal places (see `round_to`)
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - line
+    /// * `decimals` - number of decimal places to keep
+    pub fn round_line_to(
+        &self,
+        line: &LineString<f64>,
+        decimals: u32,
+    ) -> LineString<f64> {
+        line.points_iter()
+            .map(|p| self.round_to(&p, decimals))
+            .collect::<Vec<Point<f64>>>()
+            .into()
     }
 
     /// Returns a new point given easting and northing offsets (in ruler units)
@@ -244,32 +792,848 @@ impl CheapRuler {
     /// let length = cr.line_distance(&line_string);
     /// ```
     pub fn line_distance(&self, points: &LineString<f64>) -> f64 {
-        let line_iter = points.to_owned().into_iter();
+        self.line_distance_coords(&points.0)
+    }
 
-        let left = iter::once(None).chain(line_iter.clone().map(Some));
-        left.zip(line_iter)
-            .map(|(a, b)| match a {
-                Some(a) => self.distance(&a.into(), &b.into()),
-                None => 0.0,
-            })
-            .sum()
+    /// Given a slice of coordinates, returns the total line distance, like
+    /// `line_distance` but without requiring a `LineString`
+    ///
+    /// # Arguments
+    ///
+    /// * `points` - line of coordinates
+    pub fn line_distance_coords(&self, points: &[Coordinate<f64>]) -> f64 {
+        self.fold_coord_segments(points, 0.0, |sum, _, d| sum + d)
     }
 
-    /// Given a polygon returns the area
+    /// Given a line made of points, returns the total line distance like
+    /// `line_distance`, or the index of its first vertex with a non-finite
+    /// (NaN or infinite) coordinate, which would otherwise make the distance
+    /// NaN or infinite without telling which vertex is invalid
     ///
-    /// * `polygon` - Polygon
-    pub fn area(&self, polygon: &Polygon<f64>) -> f64 {
-        // FIXME: subtract interiors
-        let exterior = polygon
-            .exterior()
-            .points_iter()
+    /// # Arguments
+    ///
+    /// * `line` - Line
+    pub fn checked_line_distance(
+        &self,
+        line: &LineString<f64>,
+    ) -> Result<f64, usize> {
+        let finite = |c: &Coordinate<f64>| c.x.is_finite() && c.y.is_finite();
+        match line.0.iter().position(|c| !finite(c)) {
+            Some(i) => Err(i),
+            None => Ok(self.line_distance(line)),
+        }
+    }
+
+    /// Given a line and the elevation of each of its vertices, returns the
+    /// total slope length of the line, summing the segment lengths combined
+    /// with their elevation differences
+    ///
+    /// Panics if the number of elevations is not the number of vertices.
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - Line
+    /// * `elevations` - Elevation of each vertex, in ruler units
+    pub fn line_distance_3d(
+        &self,
+        line: &LineString<f64>,
+        elevations: &[f64],
+    ) -> f64 {
+        assert_eq!(
+            line.0.len(),
+            elevations.len(),
+            "One elevation per vertex expected"
+        );
+        self.fold_segments(line, 0.0, |sum, i, d| {
+            sum + d.hypot(elevations[i + 1] - elevations[i])
+        })
+    }
+
+    /// Folds the segments of the line in order, calling `f` with the
+    /// accumulated value, the start index of the segment and its length, to
+    /// accumulate something else than the line distance, such as travel time
+    /// given per-segment speeds
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - Line
+    /// * `init` - Initial accumulated value
+    /// * `f` - Function accumulating a segment
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheap_ruler::{CheapRuler, DistanceUnit};
+    /// use geo_types::LineString;
+    /// let cr = CheapRuler::new(50.458, DistanceUnit::Kilometers);
+    /// let line_string: LineString<f64> = vec![
+    ///     (-67.031, 50.458),
+    ///     (-67.031, 50.534),
+    ///     (-66.929, 50.534),
+    /// ].into();
+    /// let speeds = [50.0, 90.0];
+    /// let hours = cr.fold_segments(&line_string, 0.0, |total, i, d| {
+    ///     total + d / speeds[i]
+    /// });
+    /// ```
+    pub fn fold_segments<B>(
+        &self,
+        line: &LineString<f64>,
+        init: B,
+        f: impl FnMut(B, usize, f64) -> B,
+    ) -> B {
+        self.fold_coord_segments(&line.0, init, f)
+    }
+
+    fn fold_coord_segments<B>(
+        &self,
+        points: &[Coordinate<f64>],
+        init: B,
+        mut f: impl FnMut(B, usize, f64) -> B,
+    ) -> B {
+        points
+            .windows(2)
+            .enumerate()
+            .fold(init, |acc, (i, segment)| {
+                let d = self.distance(&segment[0].into(), &segment[1].into());
+                f(acc, i, d)
+            })
+    }
+
+    /// Given any number of lines, returns the sum of their distances. Returns 0
+    /// if no lines are given.
+    ///
+    /// # Arguments
+    ///
+    /// * `lines` - Lines to measure
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use cheap_ruler::{CheapRuler, DistanceUnit};
+    /// use geo_types::LineString;
+    /// let cr = CheapRuler::new(50.458, DistanceUnit::Meters);
+    /// let lines: Vec<LineString<f64>> = vec![
+    ///     vec![(-67.031, 50.458), (-67.031, 50.534)].into(),
+    ///     vec![(-66.929, 50.534), (-66.929, 50.458)].into(),
+    /// ];
+    /// let length = cr.total_length(lines.iter().take(1));
+    /// ```
+    pub fn total_length<'a, I>(&self, lines: I) -> f64
+    where
+        I: IntoIterator<Item = &'a LineString<f64>>,
+    {
+        lines.into_iter().map(|line| self.line_distance(line)).sum()
+    }
+
+    /// Given a line, returns its total distance if it does not exceed the
+    /// given maximum. Stops measuring and returns `None` as soon as the
+    /// distance measured so far exceeds `max`, avoiding work on long lines.
+    ///
+    /// A line exactly `max` long is not considered to exceed it.
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - line of points
+    /// * `max` - maximum distance
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use cheap_ruler::{CheapRuler, DistanceUnit};
+    /// use geo_types::LineString;
+    /// let cr = CheapRuler::new(50.458, DistanceUnit::Meters);
+    /// let line_string: LineString<f64> = vec![
+    ///     (-67.031, 50.458),
+    ///     (-67.031, 50.534),
+    /// ].into();
+    /// assert!(cr.line_distance_at_most(&line_string, 100.0).is_none());
+    /// assert!(cr.line_distance_at_most(&line_string, 10000.0).is_some());
+    /// ```
+    pub fn line_distance_at_most(
+        &self,
+        line: &LineString<f64>,
+        max: f64,
+    ) -> Option<f64> {
+        let mut sum = 0.0;
+        for (_, _, d) in self.iter_segments_with_distance(line) {
+            sum += d;
+            if sum > max {
+                return None;
+            }
+        }
+        Some(sum)
+    }
+
+    /// Returns a lazy iterator over the distances between each pair of
+    /// consecutive points, i.e. N - 1 distances for N points
+    ///
+    /// # Arguments
+    ///
+    /// * `points` - Points to measure between
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use cheap_ruler::{CheapRuler, DistanceUnit};
+    /// use geo_types::LineString;
+    /// let cr = CheapRuler::new(50.458, DistanceUnit::Meters);
+    /// let line_string: LineString<f64> = vec![
+    ///     (-67.031, 50.458),
+    ///     (-67.031, 50.534),
+    ///     (-66.929, 50.534),
+    /// ].into();
+    /// let longest = cr
+    ///     .distances(line_string.points_iter())
+    ///     .fold(0.0, f64::max);
+    /// ```
+    pub fn distances<'a, I>(
+        &'a self,
+        points: I,
+    ) -> impl Iterator<Item = f64> + 'a
+    where
+        I: IntoIterator<Item = Point<f64>>,
+        I::IntoIter: 'a,
+    {
+        let mut points = points.into_iter();
+        let first = points.next();
+        points.scan(first, move |previous, p| {
+            let d = previous.map(|q| self.distance(&q, &p));
+            *previous = Some(p);
+            d
+        })
+    }
+
+    /// Returns a lazy iterator over the running total of the line distance at
+    /// each vertex of the line, starting with 0 at the first vertex. The last
+    /// yielded value is the same as returned by `line_distance`.
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - Line
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheap_ruler::{CheapRuler, DistanceUnit};
+    /// use geo_types::LineString;
+    /// let cr = CheapRuler::new(50.458, DistanceUnit::Meters);
+    /// let line_string: LineString<f64> = vec![
+    ///     (-67.031, 50.458),
+    ///     (-67.031, 50.534),
+    ///     (-66.929, 50.534),
+    /// ].into();
+    /// let within_budget = cr
+    ///     .accumulate_distance(&line_string)
+    ///     .take_while(|&total| total <= 10000.0)
+    ///     .count();
+    /// assert_eq!(within_budget, 2);
+    /// ```
+    pub fn accumulate_distance<'a>(
+        &'a self,
+        line: &'a LineString<f64>,
+    ) -> impl Iterator<Item = f64> + 'a {
+        let start = line.0.first().map(|_| 0.0);
+        start.into_iter().chain(
+            self.iter_segments_with_distance(line)
+                .scan(0.0, |total, (_, _, d)| {
+                    *total += d;
+                    Some(*total)
+                }),
+        )
+    }
+
+    /// Returns an iterator over the segments of a line, yielding the start
+    /// point, end point and length of each segment. The iterator is empty for
+    /// lines with fewer than two points.
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - Line to iterate over
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheap_ruler::{CheapRuler, DistanceUnit};
+    /// use geo_types::LineString;
+    /// let cr = CheapRuler::new(50.458, DistanceUnit::Meters);
+    /// let line_string: LineString<f64> = vec![
+    ///     (-67.031, 50.458),
+    ///     (-67.031, 50.534),
+    ///     (-66.929, 50.534),
+    /// ].into();
+    /// let longest = cr
+    ///     .iter_segments_with_distance(&line_string)
+    ///     .map(|(_, _, d)| d)
+    ///     .fold(0.0, f64::max);
+    /// ```
+    pub fn iter_segments_with_distance<'a>(
+        &'a self,
+        line: &'a LineString<f64>,
+    ) -> impl Iterator<Item = (Point<f64>, Point<f64>, f64)> + 'a {
+        line.0.windows(2).map(move |segment| {
+            let a = segment[0].into();
+            let b = segment[1].into();
+            (a, b, self.distance(&a, &b))
+        })
+    }
+
+    /// Returns a copy of the line without the consecutive points lying closer
+    /// than the given tolerance to the previously kept point, such as repeated
+    /// vertices. The first and last points are always preserved.
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - Line to clean
+    /// * `tolerance` - Smallest distance between kept consecutive points
+    pub fn clean_line(
+        &self,
+        line: &LineString<f64>,
+        tolerance: f64,
+    ) -> LineString<f64> {
+        let len = line.0.len();
+        let mut cleaned: Vec<Coordinate<f64>> = Vec::with_capacity(len);
+
+        for (i, &c) in line.0.iter().enumerate() {
+            let close = match cleaned.last() {
+                Some(&last) => {
+                    self.distance(&last.into(), &c.into()) < tolerance
+                }
+                None => false,
+            };
+            if !close {
+                cleaned.push(c);
+            } else if i == len - 1 {
+                // keep the last point in place of the previous one, unless it
+                // is the first one
+                if cleaned.len() > 1 {
+                    cleaned.pop();
+                }
+                cleaned.push(c);
+            }
+        }
+
+        cleaned.into()
+    }
+
+    /// Returns true if both lines have the same number of vertices and each
+    /// vertex of one line is within the given tolerance of the corresponding
+    /// vertex of the other
+    ///
+    /// The comparison is vertex-wise: two lines tracing the same shape with
+    /// different vertices, such as a line and a resampled copy of it, are not
+    /// considered equal.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - First line
+    /// * `b` - Second line
+    /// * `tolerance` - Maximum distance between corresponding vertices
+    pub fn lines_approx_equal(
+        &self,
+        a: &LineString<f64>,
+        b: &LineString<f64>,
+        tolerance: f64,
+    ) -> bool {
+        a.0.len() == b.0.len()
+            && a.0.iter().zip(b.0.iter()).all(|(&p, &q)| {
+                self.distance(&p.into(), &q.into()) <= tolerance
+            })
+    }
+
+    /// Returns the bearing of each segment of the line, in order, with one
+    /// bearing per segment. Zero-length segments, which have no bearing, are
+    /// marked with `f64::NAN`. Lines with fewer than two points give an empty
+    /// vector.
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - Line
+    pub fn segment_bearings(&self, line: &LineString<f64>) -> Vec<f64> {
+        line.0
+            .windows(2)
+            .map(|segment| {
+                if segment[0] == segment[1] {
+                    f64::NAN
+                } else {
+                    self.bearing(&segment[0].into(), &segment[1].into())
+                }
+            })
+            .collect()
+    }
+
+    /// Flags the vertices of a trace where the heading turns by more than the
+    /// given angle, i.e. where the bearing difference (see
+    /// `bearing_difference`) between the incoming and the outgoing segments
+    /// exceeds `max_turn` in either direction. Returns one flag per vertex, the
+    /// endpoints never being flagged.
+    ///
+    /// # Arguments
+    ///
+    /// * `trace` - Trace, such as a GPS track
+    /// * `max_turn` - Largest accepted turn angle in degrees
+    pub fn flag_heading_outliers(
+        &self,
+        trace: &LineString<f64>,
+        max_turn: f64,
+    ) -> Vec<bool> {
+        let mut flags = vec![false; trace.0.len()];
+        for (i, triple) in trace.0.windows(3).enumerate() {
+            let incoming = self.bearing(&triple[0].into(), &triple[1].into());
+            let outgoing = self.bearing(&triple[1].into(), &triple[2].into());
+            let turn = bearing_difference(incoming, outgoing);
+            flags[i + 1] = turn.abs() > max_turn;
+        }
+        flags
+    }
+
+    /// Simplifies the line with the Douglas–Peucker algorithm, removing the
+    /// vertices closer than the tolerance to the simplified line, but never
+    /// removing the first and last vertices nor the vertices at the given
+    /// indices. The line is effectively simplified separately between each
+    /// pair of consecutive kept vertices. Indices out of range are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - Line to simplify
+    /// * `tolerance` - Largest distance of removed vertices to the result
+    /// * `keep` - Indices of the vertices to keep
+    pub fn simplify_keeping(
+        &self,
+        line: &LineString<f64>,
+        tolerance: f64,
+        keep: &[usize],
+    ) -> LineString<f64> {
+        let len = line.0.len();
+        if len < 3 {
+            return line.clone();
+        }
+
+        let mut kept = vec![false; len];
+        kept[0] = true;
+        kept[len - 1] = true;
+        for &i in keep.iter().filter(|&&i| i < len) {
+            kept[i] = true;
+        }
+
+        let anchors = (0..len).filter(|&i| kept[i]).collect::<Vec<usize>>();
+        for pair in anchors.windows(2) {
+            let (first, last) = (pair[0], pair[1]);
+            self.douglas_peucker(&line.0, tolerance, first, last, &mut kept);
+        }
+
+        line.0
+            .iter()
+            .zip(kept)
+            .filter(|&(_, kept)| kept)
+            .map(|(c, _)| *c)
+            .collect::<Vec<Coordinate<f64>>>()
+            .into()
+    }
+
+    /// Marks the vertices to keep strictly between `first` and `last`
+    fn douglas_peucker(
+        &self,
+        coords: &[Coordinate<f64>],
+        tolerance: f64,
+        first: usize,
+        last: usize,
+        kept: &mut [bool],
+    ) {
+        let (a, b) = (coords[first].into(), coords[last].into());
+        let mut max_dist = tolerance * tolerance;
+        let mut index = None;
+        for (i, c) in coords.iter().enumerate().take(last).skip(first + 1) {
+            let p = (*c).into();
+            let d2 = self.square_point_to_segment_distance(&p, &a, &b);
+            if d2 > max_dist {
+                max_dist = d2;
+                index = Some(i);
+            }
+        }
+
+        if let Some(i) = index {
+            kept[i] = true;
+            self.douglas_peucker(coords, tolerance, first, i, kept);
+            self.douglas_peucker(coords, tolerance, i, last, kept);
+        }
+    }
+
+    /// Joins the parts of a multi-line whose endpoints lie within the given
+    /// tolerance of each other into continuous lines, reversing parts where
+    /// needed. Parts that can't be joined with any other are returned as is.
+    ///
+    /// Where the joined endpoints are identical, the shared point is only
+    /// included once.
+    ///
+    /// # Arguments
+    ///
+    /// * `lines` - Multi-line to join the parts of
+    /// * `tolerance` - Largest distance between endpoints that are joined
+    pub fn stitch(
+        &self,
+        lines: &MultiLineString<f64>,
+        tolerance: f64,
+    ) -> Vec<LineString<f64>> {
+        let mut remaining = lines
+            .0
+            .iter()
+            .filter(|line| line.num_coords() > 0)
+            .collect::<Vec<&LineString<f64>>>();
+        let mut stitched = vec![];
+
+        while !remaining.is_empty() {
+            let mut current = remaining.remove(0).0.clone();
+
+            loop {
+                let first = current[0].into();
+                let last = current[current.len() - 1].into();
+
+                // (index, prepend, reverse) of the next part to join
+                let next = remaining.iter().enumerate().find_map(|(i, line)| {
+                    let start = line[0].into();
+                    let end = line[line.num_coords() - 1].into();
+                    if self.distance(&last, &start) <= tolerance {
+                        Some((i, false, false))
+                    } else if self.distance(&last, &end) <= tolerance {
+                        Some((i, false, true))
+                    } else if self.distance(&first, &end) <= tolerance {
+                        Some((i, true, false))
+                    } else if self.distance(&first, &start) <= tolerance {
+                        Some((i, true, true))
+                    } else {
+                        None
+                    }
+                });
+
+                let (i, prepend, reverse) = match next {
+                    Some(next) => next,
+                    None => break,
+                };
+                let mut coords = remaining.remove(i).0.clone();
+                if reverse {
+                    coords.reverse();
+                }
+                current = if prepend {
+                    join_coords(coords, current)
+                } else {
+                    join_coords(current, coords)
+                };
+            }
+
+            stitched.push(current.into());
+        }
+
+        stitched
+    }
+
+    /// Given a polygon returns the area
+    ///
+    /// * `polygon` - Polygon
+    pub fn area(&self, polygon: &Polygon<f64>) -> f64 {
+        self.area_with_orientation(polygon).0
+    }
+
+    /// Given any number of polygons, returns the sum of their areas. Returns 0
+    /// if no polygons are given.
+    ///
+    /// # Arguments
+    ///
+    /// * `polygons` - Polygons to measure
+    pub fn total_area<'a, I>(&self, polygons: I) -> f64
+    where
+        I: IntoIterator<Item = &'a Polygon<f64>>,
+    {
+        polygons.into_iter().map(|polygon| self.area(polygon)).sum()
+    }
+
+    /// Given a polygon returns the area, along with the orientation of its
+    /// exterior ring, both derived from the same signed shoelace sum. A
+    /// positive sum (in the [x = longitude, y = latitude] plane) means the
+    /// ring is clockwise, and a negative one counter-clockwise. Degenerate
+    /// rings with a sum of zero are considered counter-clockwise.
+    ///
+    /// * `polygon` - Polygon
+    pub fn area_with_orientation(
+        &self,
+        polygon: &Polygon<f64>,
+    ) -> (f64, Orientation) {
+        let exterior = polygon
+            .exterior()
+            .points_iter()
             .collect::<Vec<Point<f64>>>();
-        let mut sum = sum_area(&exterior);
+        let exterior_sum = sum_area(&exterior);
+        // holes are subtracted whatever their winding, which may or may not
+        // be opposite to the exterior's
+        let mut sum = exterior_sum.abs();
         for interior in polygon.interiors() {
             let interior = interior.points_iter().collect::<Vec<Point<f64>>>();
-            sum -= sum_area(&interior);
+            sum -= sum_area(&interior).abs();
+        }
+
+        let orientation = if exterior_sum > 0.0 {
+            Orientation::Clockwise
+        } else {
+            Orientation::CounterClockwise
+        };
+        ((sum / 2.0) * self.kx * self.ky, orientation)
+    }
+
+    /// Returns whether the exterior ring of the polygon is counter-clockwise,
+    /// as required by the GeoJSON right-hand rule. The orientation is the same
+    /// as the one returned by `area_with_orientation`.
+    ///
+    /// * `polygon` - Polygon
+    pub fn is_exterior_ccw(&self, polygon: &Polygon<f64>) -> bool {
+        self.area_with_orientation(polygon).1 == Orientation::CounterClockwise
+    }
+
+    /// Returns a copy of the polygon with its rings reordered to follow the
+    /// GeoJSON right-hand rule: the exterior ring counter-clockwise and the
+    /// interior rings clockwise. Rings that already have the right winding
+    /// are kept as they are.
+    ///
+    /// * `polygon` - Polygon
+    pub fn normalize_winding(&self, polygon: &Polygon<f64>) -> Polygon<f64> {
+        let wind = |ring: &LineString<f64>, clockwise: bool| {
+            let mut points = ring.points_iter().collect::<Vec<Point<f64>>>();
+            if !points.is_empty() && (sum_area(&points) > 0.0) != clockwise {
+                points.reverse();
+            }
+            LineString::from(points)
+        };
+
+        Polygon::new(
+            wind(polygon.exterior(), false),
+            polygon
+                .interiors()
+                .iter()
+                .map(|interior| wind(interior, true))
+                .collect(),
+        )
+    }
+
+    /// Given a polygon returns its perimeter, the sum of the lengths of its
+    /// exterior ring and of all its interior rings, including their closing
+    /// segments (polygon rings are always closed by `Polygon::new`)
+    ///
+    /// * `polygon` - Polygon
+    pub fn perimeter(&self, polygon: &Polygon<f64>) -> f64 {
+        self.line_distance(polygon.exterior())
+            + polygon
+                .interiors()
+                .iter()
+                .map(|interior| self.line_distance(interior))
+                .sum::<f64>()
+    }
+
+    /// Returns the point at a specified distance along the exterior ring of a
+    /// polygon, walking around it from its first point. The distance wraps
+    /// around the closed ring, so that distances past its length loop around
+    /// and negative distances walk back from the first point.
+    ///
+    /// Returns `None` if the exterior ring is empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `polygon` - Polygon
+    /// * `dist` - Distance along the exterior ring
+    pub fn point_on_perimeter(
+        &self,
+        polygon: &Polygon<f64>,
+        dist: f64,
+    ) -> Option<Point<f64>> {
+        let exterior = polygon.exterior();
+        let length = self.line_distance(exterior);
+        let dist = if length > 0.0 {
+            dist.rem_euclid(length)
+        } else {
+            0.0
+        };
+        self.along(exterior, dist)
+    }
+
+    /// Estimates the area of the overlap between two convex polygons as a
+    /// fraction of the area of the smaller one, from 0 (disjoint) to 1 (the
+    /// smaller polygon is inside the larger one). The exterior rings are
+    /// clipped against each other with the Sutherland–Hodgman algorithm.
+    ///
+    /// Only the exterior rings are considered, and they must be convex:
+    /// results for concave polygons are meaningless. Returns 0 if either
+    /// polygon has no area.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - First convex polygon
+    /// * `b` - Second convex polygon
+    pub fn overlap_ratio(&self, a: &Polygon<f64>, b: &Polygon<f64>) -> f64 {
+        let origin = match a.exterior().0.first() {
+            Some(&origin) => origin,
+            None => return 0.0,
+        };
+        let local = |polygon: &Polygon<f64>| {
+            let ring = &polygon.exterior().0;
+            // drop the closing point, trailing repeated points included
+            let open = match ring.iter().rposition(|c| c != &ring[0]) {
+                Some(last) => &ring[..=last],
+                None => &ring[..0],
+            };
+            let mut ring = open
+                .iter()
+                .map(|c| self.to_local(&origin, *c))
+                .collect::<Vec<(f64, f64)>>();
+            if shoelace(&ring) < 0.0 {
+                ring.reverse();
+            }
+            ring
+        };
+        let (ring_a, ring_b) = (local(a), local(b));
+
+        let smaller = shoelace(&ring_a).min(shoelace(&ring_b));
+        if smaller <= 0.0 {
+            return 0.0;
+        }
+
+        let mut clipped = ring_a;
+        for i in 0..ring_b.len() {
+            let (e0, e1) = (ring_b[i], ring_b[(i + 1) % ring_b.len()]);
+            let side = |p: (f64, f64)| {
+                (e1.0 - e0.0) * (p.1 - e0.1) - (e1.1 - e0.1) * (p.0 - e0.0)
+            };
+            let input = mem::take(&mut clipped);
+            for j in 0..input.len() {
+                let (p, q) = (input[j], input[(j + 1) % input.len()]);
+                let (sp, sq) = (side(p), side(q));
+                if sp >= 0.0 {
+                    clipped.push(p);
+                }
+                if (sp >= 0.0) != (sq >= 0.0) {
+                    let t = sp / (sp - sq);
+                    clipped
+                        .push((p.0 + t * (q.0 - p.0), p.1 + t * (q.1 - p.1)));
+                }
+            }
+        }
+
+        (shoelace(&clipped) / smaller).min(1.0)
+    }
+
+    /// Estimates the length of the portion of line `a` that runs within the
+    /// given tolerance of line `b`, summing the lengths of the segments of `a`
+    /// whose endpoints are both within `tolerance` of `b`.
+    ///
+    /// This is a heuristic: only vertices of `a` are tested, so a segment whose
+    /// endpoints are close to `b` but which strays from it in between still
+    /// counts, and partially overlapping segments don't count at all. Denser
+    /// lines give more accurate results.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - Line to measure the overlapping portion of
+    /// * `b` - Line to compare against
+    /// * `tolerance` - Largest distance from `b` considered overlapping
+    pub fn overlap_length(
+        &self,
+        a: &LineString<f64>,
+        b: &LineString<f64>,
+        tolerance: f64,
+    ) -> f64 {
+        if b.num_coords() < 2 {
+            return 0.0;
+        }
+
+        let near = a
+            .0
+            .iter()
+            .map(|c| {
+                let p = (*c).into();
+                match self.point_on_line(b, &p) {
+                    Some(pol) => pol.dist() <= tolerance,
+                    None => false,
+                }
+            })
+            .collect::<Vec<bool>>();
+
+        self.iter_segments_with_distance(a)
+            .zip(near.windows(2))
+            .filter(|(_, near)| near[0] && near[1])
+            .map(|((_, _, d), _)| d)
+            .sum()
+    }
+
+    /// Given a polygon returns its area on a sphere with the mean WGS84
+    /// radius, computed from the spherical excess of its rings.
+    ///
+    /// This is slower than `area`, but does not degrade for larger polygons,
+    /// making it useful for gauging the error of the fast approximation. For
+    /// city-scale polygons near the ruler's latitude the two typically agree
+    /// within 1%, the difference being mostly due to the spherical model.
+    ///
+    /// # Arguments
+    ///
+    /// * `polygon` - Polygon
+    pub fn spherical_area(&self, polygon: &Polygon<f64>) -> f64 {
+        // holes are subtracted whatever their winding
+        let mut sum = spherical_excess(&polygon.exterior().0).abs();
+        for interior in polygon.interiors() {
+            sum -= spherical_excess(&interior.0).abs();
+        }
+        let factor = self.distance_unit.conversion_factor_kilometers();
+        sum * RM * RM * factor * factor
+    }
+
+    /// Measures any geometry, returning the length of linear geometries and
+    /// the area of areal ones. Points have no extent and measure as
+    /// `Measurement::Zero`.
+    ///
+    /// Geometry collections are measured recursively, summing the lengths and
+    /// areas of their members separately. A collection containing both linear
+    /// and areal geometries measures as `Measurement::Mixed`.
+    ///
+    /// # Arguments
+    ///
+    /// * `geom` - Geometry to measure
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheap_ruler::{CheapRuler, DistanceUnit, Measurement};
+    /// use geo_types::{Geometry, LineString};
+    /// let cr = CheapRuler::new(50.458, DistanceUnit::Meters);
+    /// let line_string: LineString<f64> =
+    ///     vec![(-67.031, 50.458), (-67.031, 50.534)].into();
+    /// let length = cr.line_distance(&line_string);
+    /// let measurement = cr.measure(&Geometry::LineString(line_string));
+    /// assert_eq!(measurement, Measurement::Length(length));
+    /// ```
+    pub fn measure(&self, geom: &Geometry<f64>) -> Measurement {
+        match geom {
+            Geometry::Point(_) | Geometry::MultiPoint(_) => Measurement::Zero,
+            Geometry::Line(line) => Measurement::Length(
+                self.distance(&line.start_point(), &line.end_point()),
+            ),
+            Geometry::LineString(line) => {
+                Measurement::Length(self.line_distance(line))
+            }
+            Geometry::MultiLineString(lines) => Measurement::Length(
+                lines.0.iter().map(|line| self.line_distance(line)).sum(),
+            ),
+            Geometry::Polygon(polygon) => Measurement::Area(self.area(polygon)),
+            Geometry::MultiPolygon(polygons) => Measurement::Area(
+                polygons.0.iter().map(|polygon| self.area(polygon)).sum(),
+            ),
+            Geometry::Rect(rect) => {
+                Measurement::Area(self.area(&rect.to_polygon()))
+            }
+            Geometry::Triangle(triangle) => {
+                Measurement::Area(self.area(&triangle.to_polygon()))
+            }
+            Geometry::GeometryCollection(collection) => collection
+                .0
+                .iter()
+                .map(|geom| self.measure(geom))
+                .fold(Measurement::Zero, Measurement::combine),
         }
-        (sum.abs() / 2.0) * self.kx * self.ky
     }
 
     /// Returns the point at a specified distance along the line
@@ -282,14 +1646,264 @@ impl CheapRuler {
         &self,
         line: &LineString<f64>,
         dist: f64,
-    ) -> Option<Point<f64>> {
-        let line_len = line.num_coords();
+    ) -> Option<Point<f64>> {
+        self.along_coords(&line.0, dist)
+    }
+
+    /// Returns the point at a specified distance along a slice of coordinates,
+    /// like `along` but without requiring a `LineString`
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - Line of coordinates
+    /// * `dist` - Distance along the line
+    pub fn along_coords(
+        &self,
+        line: &[Coordinate<f64>],
+        dist: f64,
+    ) -> Option<Point<f64>> {
+        self.along_with_index_coords(line, dist)
+            .map(|(point, _)| point)
+    }
+
+    /// Returns the point at a specified distance along a multi line string,
+    /// treating its parts as a single continuous path in the order they are
+    /// given. Gaps between the end of a part and the start of the next one
+    /// contribute zero length, so the point jumps from one part to the next.
+    ///
+    /// Returns `None` if all of the parts are empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `lines` - Multi line string
+    /// * `dist` - Distance along the joined parts
+    pub fn multiline_along(
+        &self,
+        lines: &MultiLineString<f64>,
+        dist: f64,
+    ) -> Option<Point<f64>> {
+        let mut sum = 0.0;
+        let mut last = None;
+
+        for line in lines.0.iter().filter(|line| !line.0.is_empty()) {
+            let len = self.line_distance(line);
+            if sum + len > dist {
+                return self.along(line, dist - sum);
+            }
+            sum += len;
+            last = Some(line);
+        }
+
+        last.and_then(|line| line.points_iter().next_back())
+    }
+
+    /// Returns the points at each of the specified distances along the line,
+    /// in the same order as the distances, walking the line only once. Each
+    /// point is the same as the one returned by `along` for its distance.
+    ///
+    /// The distances don't need to be sorted, but if they are sorted in
+    /// ascending order no additional work is needed to sort them internally.
+    /// NaN distances give the last point of the line, like with `along`, and
+    /// don't affect the other results.
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - Line
+    /// * `dists` - Distances along the line
+    pub fn along_many(
+        &self,
+        line: &LineString<f64>,
+        dists: &[f64],
+    ) -> Vec<Option<Point<f64>>> {
+        let mut points = vec![None; dists.len()];
+        let line_len = line.num_coords();
+        if line_len == 0 {
+            return points;
+        }
+
+        let mut order = (0..dists.len()).collect::<Vec<usize>>();
+        // NaN distances are sorted last, so that the line is walked to its
+        // end only once all the other distances are handled
+        order.sort_by(|&a, &b| {
+            let (a, b) = (dists[a], dists[b]);
+            match (a.is_nan(), b.is_nan()) {
+                (false, false) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+                (a_nan, b_nan) => a_nan.cmp(&b_nan),
+            }
+        });
+
+        let first = line[0].into();
+        let last = line[line_len - 1].into();
+        let mut segments = self.iter_segments_with_distance(line);
+        let mut segment = segments.next();
+        let mut sum = segment.map_or(0.0, |(_, _, d)| d);
+
+        for i in order {
+            let dist = dists[i];
+            if dist <= 0.0 {
+                points[i] = Some(first);
+                continue;
+            }
+
+            points[i] = loop {
+                match segment {
+                    Some((p0, p1, d)) if sum > dist => {
+                        let t = (dist - (sum - d)) / d;
+                        break Some(interpolate(&p0, &p1, t));
+                    }
+                    Some(_) => {
+                        segment = segments.next();
+                        sum += segment.map_or(0.0, |(_, _, d)| d);
+                    }
+                    None => break Some(last),
+                }
+            };
+        }
+
+        points
+    }
+
+    /// Resamples a line into the given number of points evenly spaced along it,
+    /// from its first point to its last one, returning the resampled line with
+    /// the distance along the original line of each of its points
+    ///
+    /// The distances go from 0 to the length of the line, so that each point
+    /// can be mapped back to the original line. An empty line gives an empty
+    /// line and no distances.
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - Line to resample
+    /// * `n` - Number of points, at least 2
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is less than 2.
+    pub fn resample_with_distances(
+        &self,
+        line: &LineString<f64>,
+        n: usize,
+    ) -> (LineString<f64>, Vec<f64>) {
+        assert!(n >= 2, "At least 2 points expected");
+        if line.0.is_empty() {
+            return (line_string![], vec![]);
+        }
+
+        let length = self.line_distance(line);
+        let step = length / (n - 1) as f64;
+        let dists = (0..n).map(|i| i as f64 * step).collect::<Vec<f64>>();
+        let resampled = self
+            .along_many(line, &dists)
+            .into_iter()
+            .flatten()
+            .collect::<Vec<Point<f64>>>();
+
+        (resampled.into(), dists)
+    }
+
+    /// Returns the point at a specified distance along the line, along with
+    /// the start index of the segment it lies on (like the index returned by
+    /// `point_on_line`)
+    ///
+    /// Distances of 0 or less give the first point and index 0, while
+    /// distances past the end of the line give the last point and the start
+    /// index of the last segment.
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - Line
+    /// * `dist` - Distance along the line
+    pub fn along_with_index(
+        &self,
+        line: &LineString<f64>,
+        dist: f64,
+    ) -> Option<(Point<f64>, usize)> {
+        self.along_with_index_coords(&line.0, dist)
+    }
+
+    /// Returns the bearing of the line segment at a specified distance along
+    /// the line, or `None` for lines with fewer than two points
+    ///
+    /// Distances are clamped to the extent of the line like with
+    /// `along_with_index`: distances of 0 or less give the bearing of the first
+    /// segment, and distances past the end of the line the bearing of the last
+    /// one.
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - Line
+    /// * `dist` - Distance along the line
+    pub fn bearing_at(&self, line: &LineString<f64>, dist: f64) -> Option<f64> {
+        if line.0.len() < 2 {
+            return None;
+        }
+        let (_, i) = self.along_with_index(line, dist)?;
+        Some(self.bearing(&line[i].into(), &line[i + 1].into()))
+    }
+
+    /// Returns cross-section segments perpendicular to the line at regular
+    /// intervals along it, starting at its first point. Each segment is
+    /// centered on the line and goes from the left side of the line to its
+    /// right side, relative to the direction of travel of the segment it
+    /// crosses.
+    ///
+    /// Returns no cross-sections for lines with fewer than two points.
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - Line
+    /// * `interval` - Distance between consecutive cross-sections, must be
+    ///   positive
+    /// * `half_width` - Distance of the ends of each cross-section from the
+    ///   line
+    ///
+    /// # Panics
+    ///
+    /// Panics if `interval` is not greater than 0.
+    pub fn cross_sections(
+        &self,
+        line: &LineString<f64>,
+        interval: f64,
+        half_width: f64,
+    ) -> Vec<Line<f64>> {
+        assert!(interval > 0.0, "Interval must be positive");
+        if line.0.len() < 2 {
+            return vec![];
+        }
+
+        let length = self.line_distance(line);
+        let mut sections = vec![];
+        let mut i = 0;
+        loop {
+            let dist = i as f64 * interval;
+            if dist > length {
+                break;
+            }
+            if let Some((center, index)) = self.along_with_index(line, dist) {
+                let bearing = self
+                    .bearing(&line[index].into(), &line[index + 1].into());
+                sections.push(Line::new(
+                    self.destination(&center, half_width, bearing - 90.0),
+                    self.destination(&center, half_width, bearing + 90.0),
+                ));
+            }
+            i += 1;
+        }
+        sections
+    }
+
+    fn along_with_index_coords(
+        &self,
+        line: &[Coordinate<f64>],
+        dist: f64,
+    ) -> Option<(Point<f64>, usize)> {
+        let line_len = line.len();
         if line_len == 0 {
             return None;
         }
 
         if dist <= 0.0 {
-            return Some(line[0].into());
+            return Some((line[0].into(), 0));
         }
 
         let last_index = line_len - 1;
@@ -300,10 +1914,11 @@ impl CheapRuler {
             let d = self.distance(p0, p1);
             sum += d;
             if sum > dist {
-                return Some(interpolate(p0, p1, (dist - (sum - d)) / d));
+                let t = (dist - (sum - d)) / d;
+                return Some((interpolate(p0, p1, t), i));
             }
         }
-        Some(line[last_index].into())
+        Some((line[last_index].into(), last_index.saturating_sub(1)))
     }
 
     /// Returns the shortest distance between a point and a line segment given
@@ -320,6 +1935,53 @@ impl CheapRuler {
         start: &Point<f64>,
         end: &Point<f64>,
     ) -> f64 {
+        self.square_point_to_segment_distance(p, start, end).sqrt()
+    }
+
+    /// Returns the squared shortest distance between a point and a line
+    /// segment given with two points. This is cheaper than
+    /// `point_to_segment_distance` when only comparing distances.
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - Point to calculate the distance from
+    /// * `start` - Start point of line segment
+    /// * `end` - End point of line segment
+    pub fn square_point_to_segment_distance(
+        &self,
+        p: &Point<f64>,
+        start: &Point<f64>,
+        end: &Point<f64>,
+    ) -> f64 {
+        self.square_distance(p, &self.segment_foot(p, start, end))
+    }
+
+    /// Returns a tuple of the form (point, distance) where point is the closest
+    /// point on a line segment given with two points, and distance is the
+    /// shortest distance between the given point and the segment (the same as
+    /// returned by `point_to_segment_distance`).
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - Point to find the closest point on the segment to
+    /// * `start` - Start point of line segment
+    /// * `end` - End point of line segment
+    pub fn closest_point_on_segment(
+        &self,
+        p: &Point<f64>,
+        start: &Point<f64>,
+        end: &Point<f64>,
+    ) -> (Point<f64>, f64) {
+        let closest = self.segment_foot(p, start, end);
+        (closest, self.distance(p, &closest))
+    }
+
+    fn segment_foot(
+        &self,
+        p: &Point<f64>,
+        start: &Point<f64>,
+        end: &Point<f64>,
+    ) -> Point<f64> {
         let mut x = start.lng();
         let mut y = start.lat();
         let dx = long_diff(end.lng(), x) * self.kx;
@@ -337,7 +1999,79 @@ impl CheapRuler {
                 y += (dy / self.ky) * t;
             }
         }
-        self.distance(&p, &point!(x: x, y: y))
+        point!(x: x, y: y)
+    }
+
+    /// Returns the start index of the line segment closest to the given point,
+    /// the same as the index returned by `point_on_line`, but without
+    /// computing the projected point. Returns `None` for lines with fewer than
+    /// two points.
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - Line to compare with point
+    /// * `point` - Point to find the closest segment to
+    pub fn nearest_segment(
+        &self,
+        line: &LineString<f64>,
+        point: &Point<f64>,
+    ) -> Option<usize> {
+        let mut min_dist = f64::INFINITY;
+        let mut min_i = None;
+
+        for (i, segment) in line.0.windows(2).enumerate() {
+            let d2 = self.square_point_to_segment_distance(
+                point,
+                &segment[0].into(),
+                &segment[1].into(),
+            );
+            if d2 < min_dist {
+                min_dist = d2;
+                min_i = Some(i);
+            }
+        }
+
+        min_i
+    }
+
+    /// Returns up to `k` vertices of the line closest to the given point, as
+    /// (index, distance) tuples sorted by ascending distance. Vertices at the
+    /// same distance are sorted by index. Vertices with non-finite (NaN)
+    /// coordinates are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - Line to compare with point
+    /// * `point` - Point to find the closest vertices to
+    /// * `k` - Maximum number of vertices to return
+    pub fn nearest_vertices(
+        &self,
+        line: &LineString<f64>,
+        point: &Point<f64>,
+        k: usize,
+    ) -> Vec<(usize, f64)> {
+        let mut nearest: Vec<(usize, f64)> =
+            Vec::with_capacity(k.min(line.0.len()));
+        if k == 0 {
+            return nearest;
+        }
+
+        for (i, c) in line.0.iter().enumerate() {
+            let d2 = self.square_distance(point, &(*c).into());
+            if d2.is_nan() || nearest.len() == k && d2 >= nearest[k - 1].1 {
+                continue;
+            }
+            if nearest.len() == k {
+                nearest.pop();
+            }
+            let position = nearest
+                .iter()
+                .position(|&(_, other)| d2 < other)
+                .unwrap_or(nearest.len());
+            nearest.insert(position, (i, d2));
+        }
+
+        nearest.iter().map(|&(i, d2)| (i, d2.sqrt())).collect()
     }
 
     /// Returns a tuple of the form (point, index, t) where point is closest
@@ -354,18 +2088,119 @@ impl CheapRuler {
         line: &LineString<f64>,
         point: &Point<f64>,
     ) -> Option<PointOnLine<f64>> {
+        self.point_on_line_coords(&line.0, point)
+    }
+
+    /// Snaps a point to the closest point on the line, then moves it the given
+    /// distance perpendicular to the segment it lies on, e.g. to place a label
+    /// beside a route. Positive offsets are to the right of the direction of
+    /// travel and negative ones to the left.
+    ///
+    /// Returns `None` if the line has fewer than two points.
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - Line to snap to
+    /// * `point` - Point to snap to the line
+    /// * `offset` - Distance from the line
+    pub fn offset_from_line(
+        &self,
+        line: &LineString<f64>,
+        point: &Point<f64>,
+        offset: f64,
+    ) -> Option<Point<f64>> {
+        if line.0.len() < 2 {
+            return None;
+        }
+        let pol = self.point_on_line(line, point)?;
+        let i = pol.index();
+        let bearing = self.bearing(&line.0[i].into(), &line.0[i + 1].into());
+        Some(self.destination(&pol.point(), offset, bearing + 90.0))
+    }
+
+    /// Returns the closest point on a slice of coordinates from the given
+    /// point, like `point_on_line` but without requiring a `LineString`
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - Line of coordinates to compare with point
+    /// * `point` - Point to calculate the closest point on the line
+    pub fn point_on_line_coords(
+        &self,
+        line: &[Coordinate<f64>],
+        point: &Point<f64>,
+    ) -> Option<PointOnLine<f64>> {
+        let segments = line.len().saturating_sub(1);
+        self.closest_on_segments(line, point, 0, segments)
+            .map(|(pol, _)| pol)
+    }
+
+    /// Returns the closest point on the line from the given point like
+    /// `point_on_line`, but only searching the segments with start indices
+    /// from `lo` to `hi` (inclusive). The range is clamped to the segments of
+    /// the line.
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - Line to compare with point
+    /// * `point` - Point to calculate the closest point on the line
+    /// * `lo` - Start index of the first segment to search
+    /// * `hi` - Start index of the last segment to search
+    pub fn point_on_line_range(
+        &self,
+        line: &LineString<f64>,
+        point: &Point<f64>,
+        lo: usize,
+        hi: usize,
+    ) -> Option<PointOnLine<f64>> {
+        let segments = line.0.len().saturating_sub(1);
+        let end = hi.saturating_add(1).min(segments);
+        let start = lo.min(end.saturating_sub(1));
+        self.closest_on_segments(&line.0, point, start, end)
+            .map(|(pol, _)| pol)
+    }
+
+    /// Returns the closest point on the line from the given point like
+    /// `point_on_line`, or `None` if it is farther than `max_distance` from
+    /// the point
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - Line to compare with point
+    /// * `point` - Point to calculate the closest point on the line
+    /// * `max_distance` - Largest accepted distance to the line
+    pub fn snap_within(
+        &self,
+        line: &LineString<f64>,
+        point: &Point<f64>,
+        max_distance: f64,
+    ) -> Option<PointOnLine<f64>> {
+        let segments = line.0.len().saturating_sub(1);
+        self.closest_on_segments(&line.0, point, 0, segments)
+            .filter(|&(_, d2)| d2 <= max_distance * max_distance)
+            .map(|(pol, _)| pol)
+    }
+
+    /// Closest point on the segments with start indices from `lo` to `hi`
+    /// (exclusive), along with its squared distance to the point
+    fn closest_on_segments(
+        &self,
+        line: &[Coordinate<f64>],
+        point: &Point<f64>,
+        lo: usize,
+        hi: usize,
+    ) -> Option<(PointOnLine<f64>, f64)> {
         let mut min_dist = f64::INFINITY;
         let mut min_x = 0.0;
         let mut min_y = 0.0;
         let mut min_i = 0;
         let mut min_t = 0.0;
 
-        let line_len = line.num_coords();
-        if line_len == 0 {
+        if line.is_empty() {
             return None;
         }
 
-        for i in 0..line_len - 1 {
+        for i in lo..hi {
             let mut t = 0.0;
             let mut x = line[i].x;
             let mut y = line[i].y;
@@ -386,7 +2221,7 @@ impl CheapRuler {
                 }
             }
 
-            let d2 = self.square_distance(&point, &point!(x: x, y: y));
+            let d2 = self.square_distance(point, &point!(x: x, y: y));
 
             if d2 < min_dist {
                 min_dist = d2;
@@ -397,11 +2232,153 @@ impl CheapRuler {
             }
         }
 
-        Some(PointOnLine::new(
+        let pol = PointOnLine::new(
             point!(x: min_x, y: min_y),
             min_i,
             0f64.max(1f64.min(min_t)),
-        ))
+            min_dist.sqrt(),
+        );
+        Some((pol, min_dist))
+    }
+
+    /// Snaps a point to the closest of several labeled lines, returning the
+    /// label of the closest line along with the closest point on it (see
+    /// `point_on_line`). If several lines are equally close, the first one
+    /// wins.
+    ///
+    /// Lines with fewer than two points are ignored, and `None` is returned
+    /// if no line remains.
+    ///
+    /// # Arguments
+    ///
+    /// * `lines` - Lines to snap to, each with a label such as an id
+    /// * `p` - Point to snap
+    pub fn snap_to_network<T: Copy>(
+        &self,
+        lines: &[(T, LineString<f64>)],
+        p: &Point<f64>,
+    ) -> Option<(T, PointOnLine<f64>)> {
+        let mut min_dist = f64::INFINITY;
+        let mut closest = None;
+
+        for (label, line) in lines {
+            if line.num_coords() < 2 {
+                continue;
+            }
+            let segments = line.0.len() - 1;
+            let (pol, d2) =
+                match self.closest_on_segments(&line.0, p, 0, segments) {
+                    Some(closest) => closest,
+                    None => continue,
+                };
+            if d2 < min_dist {
+                min_dist = d2;
+                closest = Some((*label, pol));
+            }
+        }
+
+        closest
+    }
+
+    /// Returns the closest point on the line from the given point (see
+    /// `point_on_line`) along with the side of the line the given point lies
+    /// on, relative to the direction of the segment with the closest point.
+    ///
+    /// Returns `None` if the line has fewer than two points.
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - Line to compare with point
+    /// * `point` - Point to find the side of
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheap_ruler::{CheapRuler, DistanceUnit, Side};
+    /// use geo_types::LineString;
+    /// let cr = CheapRuler::new(44.7192003, DistanceUnit::Meters);
+    /// let line: LineString<f64> = vec![(14.89, 44.72), (14.90, 44.72)].into();
+    /// let (_, side) = cr.point_side(&line, &(14.895, 44.73).into()).unwrap();
+    /// assert_eq!(side, Side::Left);
+    /// ```
+    pub fn point_side(
+        &self,
+        line: &LineString<f64>,
+        point: &Point<f64>,
+    ) -> Option<(PointOnLine<f64>, Side)> {
+        if line.num_coords() < 2 {
+            return None;
+        }
+
+        let pol = self.point_on_line(line, point)?;
+        let start = line[pol.index()];
+        let end = line[pol.index() + 1];
+
+        let dx = long_diff(end.x, start.x) * self.kx;
+        let dy = (end.y - start.y) * self.ky;
+        let px = long_diff(point.lng(), start.x) * self.kx;
+        let py = (point.lat() - start.y) * self.ky;
+
+        let cross = dx * py - dy * px;
+        let side = if cross > 0.0 {
+            Side::Left
+        } else if cross < 0.0 {
+            Side::Right
+        } else {
+            Side::On
+        };
+
+        Some((pol, side))
+    }
+
+    /// Returns the distance along the line between two points on it, such as
+    /// the ones returned by `point_on_line`. It is the same as the length of
+    /// the `line_slice` between them, without building the slice.
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - Line string
+    /// * `a` - First point on the line
+    /// * `b` - Second point on the line
+    pub fn distance_between_on_line(
+        &self,
+        line: &LineString<f64>,
+        a: &PointOnLine<f64>,
+        b: &PointOnLine<f64>,
+    ) -> f64 {
+        let along = |pol: &PointOnLine<f64>| {
+            self.iter_segments_with_distance(line)
+                .take(pol.index() + 1)
+                .enumerate()
+                .map(|(i, (_, _, d))| {
+                    if i == pol.index() {
+                        d * pol.t()
+                    } else {
+                        d
+                    }
+                })
+                .sum::<f64>()
+        };
+
+        (along(b) - along(a)).abs()
+    }
+
+    /// Returns the distance along the line from its start to the closest point
+    /// on it from the given point (see `point_on_line`), or `None` for empty
+    /// lines
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - Line string
+    /// * `point` - Point to measure the progress along the line of
+    pub fn distance_to_point_along(
+        &self,
+        line: &LineString<f64>,
+        point: &Point<f64>,
+    ) -> Option<f64> {
+        let pol = self.point_on_line(line, point)?;
+        let start = PointOnLine::new(line[0].into(), 0, 0.0, 0.0);
+        Some(self.distance_between_on_line(line, &start, &pol))
     }
 
     /// Returns a part of the given line between the start and the stop points
@@ -418,8 +2395,25 @@ impl CheapRuler {
         stop: &Point<f64>,
         line: &LineString<f64>,
     ) -> LineString<f64> {
-        let pol1 = self.point_on_line(line, start);
-        let pol2 = self.point_on_line(line, stop);
+        self.line_slice_coords(start, stop, &line.0)
+    }
+
+    /// Returns a part of the given slice of coordinates between the start and
+    /// the stop points, like `line_slice` but without requiring a `LineString`
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - Start point
+    /// * `stop` - Stop point
+    /// * `line` - Line of coordinates
+    pub fn line_slice_coords(
+        &self,
+        start: &Point<f64>,
+        stop: &Point<f64>,
+        line: &[Coordinate<f64>],
+    ) -> LineString<f64> {
+        let pol1 = self.point_on_line_coords(line, start);
+        let pol2 = self.point_on_line_coords(line, stop);
 
         if pol1.is_none() || pol2.is_none() {
             return line_string![];
@@ -455,47 +2449,359 @@ impl CheapRuler {
         slice.into()
     }
 
+    /// Splits the line in two at the closest point on it from the given point
+    /// (see `point_on_line`). The closest point ends the first part and starts
+    /// the second one, without being repeated when it is already a vertex.
+    ///
+    /// Lines with fewer than two points are returned as both parts.
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - Line string
+    /// * `point` - Point to split the line at
+    pub fn split_at_point(
+        &self,
+        line: &LineString<f64>,
+        point: &Point<f64>,
+    ) -> (LineString<f64>, LineString<f64>) {
+        if line.0.len() < 2 {
+            return (line.clone(), line.clone());
+        }
+        let pol = self
+            .point_on_line(line, point)
+            .expect("Non-empty line string given");
+        let split: Coordinate<f64> = pol.point().into();
+        let i = pol.index();
+
+        let mut head = line.0[..=i].to_vec();
+        if head[i] != split {
+            head.push(split);
+        }
+
+        let mut tail = vec![split];
+        if line[i + 1] != split {
+            tail.push(line[i + 1]);
+        }
+        tail.extend_from_slice(&line.0[i + 2..]);
+
+        (head.into(), tail.into())
+    }
+
+    /// Returns a copy of the line prolonged past its last point by the given
+    /// distance, following the bearing of its last segment. Repeated points at
+    /// the end of the line are skipped to find that bearing.
+    ///
+    /// The line is returned unchanged when the distance is 0 or less, or when
+    /// it has no segment of non-zero length.
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - Line string
+    /// * `distance` - Distance to prolong the line by
+    pub fn extend(
+        &self,
+        line: &LineString<f64>,
+        distance: f64,
+    ) -> LineString<f64> {
+        let mut extended = line.clone();
+        if distance <= 0.0 {
+            return extended;
+        }
+
+        let last = match line.0.last() {
+            Some(&last) => last,
+            None => return extended,
+        };
+        if let Some(&previous) = line.0.iter().rev().find(|&&c| c != last) {
+            let bearing = self.bearing(&previous.into(), &last.into());
+            let end = self.destination(&last.into(), distance, bearing);
+            extended.0.push(end.into());
+        }
+        extended
+    }
+
     /// Returns a part of the given line between the start and the stop points
     /// indicated by distance along the line
     ///
     /// * `start` - Start distance
     /// * `stop` - Stop distance
     /// * `line` - Line string
-    pub fn line_slice_along(
+    pub fn line_slice_along(
+        &self,
+        start: f64,
+        stop: f64,
+        line: &LineString<f64>,
+    ) -> LineString<f64> {
+        self.line_slice_along_coords(start, stop, &line.0)
+    }
+
+    /// Returns a part of the given slice of coordinates between the start and
+    /// the stop points indicated by distance along the line, like
+    /// `line_slice_along` but without requiring a `LineString`
+    ///
+    /// * `start` - Start distance
+    /// * `stop` - Stop distance
+    /// * `line` - Line of coordinates
+    pub fn line_slice_along_coords(
+        &self,
+        start: f64,
+        stop: f64,
+        line: &[Coordinate<f64>],
+    ) -> LineString<f64> {
+        let mut sum = 0.0;
+        let mut slice = vec![];
+
+        if line.is_empty() {
+            return slice.into();
+        }
+
+        for i in 0..line.len() - 1 {
+            let p0 = line[i].into();
+            let p1 = line[i + 1].into();
+            let d = self.distance(&p0, &p1);
+
+            sum += d;
+
+            if sum > start && slice.is_empty() {
+                slice.push(interpolate(&p0, &p1, (start - (sum - d)) / d));
+            }
+
+            if sum >= stop {
+                slice.push(interpolate(&p0, &p1, (stop - (sum - d)) / d));
+                return slice.into();
+            }
+
+            if sum > start {
+                slice.push(p1);
+            }
+        }
+
+        slice.into()
+    }
+
+    /// Like `line_slice_along`, but clamps the start and stop distances to the
+    /// extent of the line first, and returns a tuple of the form
+    /// (slice, start, stop) with the effective distances that were used
+    ///
+    /// The start is clamped between 0 and the line distance, and the stop
+    /// between the clamped start and the line distance.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - Start distance
+    /// * `stop` - Stop distance
+    /// * `line` - Line string
+    pub fn line_slice_along_clamped(
         &self,
         start: f64,
         stop: f64,
         line: &LineString<f64>,
+    ) -> (LineString<f64>, f64, f64) {
+        let total = self.line_distance(line);
+        let start = start.max(0.0).min(total);
+        let stop = stop.max(start).min(total);
+        (self.line_slice_along(start, stop, line), start, stop)
+    }
+
+    /// Returns the part of the line remaining after removing the given lengths
+    /// from its start and its end, using `line_slice_along`. Negative lengths
+    /// are treated as 0.
+    ///
+    /// Returns an empty line when the trimmed lengths add up to the whole line
+    /// distance or more.
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - Line string
+    /// * `start_trim` - Length to remove from the start of the line
+    /// * `end_trim` - Length to remove from the end of the line
+    pub fn trim(
+        &self,
+        line: &LineString<f64>,
+        start_trim: f64,
+        end_trim: f64,
     ) -> LineString<f64> {
-        let mut sum = 0.0;
-        let mut slice = vec![];
+        let (start_trim, end_trim) = (start_trim.max(0.0), end_trim.max(0.0));
+        let length = self.line_distance(line);
+        if start_trim + end_trim >= length {
+            return line_string![];
+        }
+        self.line_slice_along(start_trim, length - end_trim, line)
+    }
 
-        if line.num_coords() == 0 {
-            return slice.into();
+    /// Given a line, returns a polygon covering the corridor within the given
+    /// distance of it on both sides, with flat caps at its ends. Sharp turns
+    /// are beveled instead of mitered.
+    ///
+    /// Returns a polygon with an empty exterior if the line has fewer than two
+    /// distinct points.
+    ///
+    /// The offsets of both sides are used as is, so the exterior ring crosses
+    /// itself where the line turns back within twice the buffer distance of
+    /// itself, such as at tight hairpins, or where it crosses itself. Point in
+    /// polygon tests like `contains_point` then give wrong answers in the
+    /// overlapping parts. To check whether points are within the distance of
+    /// the line, compare the distance of their closest point on the line (see
+    /// `point_on_line`) instead, keeping in mind that it gives round ends
+    /// rather than flat caps.
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - Line
+    /// * `distance` - Buffer distance on each side of the line
+    pub fn buffer_line(
+        &self,
+        line: &LineString<f64>,
+        distance: f64,
+    ) -> Polygon<f64> {
+        let mut ring = self.offset_vertices(&line.0, distance);
+        if ring.is_empty() {
+            return Polygon::new(line_string![], vec![]);
         }
 
-        for i in 0..line.num_coords() - 1 {
-            let p0 = line[i].into();
-            let p1 = line[i + 1].into();
-            let d = self.distance(&p0, &p1);
+        let mut right = self.offset_vertices(&line.0, -distance);
+        right.reverse();
+        ring.extend(right);
 
-            sum += d;
+        Polygon::new(ring.into(), vec![])
+    }
 
-            if sum > start && slice.is_empty() {
-                slice.push(interpolate(&p0, &p1, (start - (sum - d)) / d));
-            }
+    /// Offsets the vertices of a line by the given distance, to the left of
+    /// the direction of travel for positive distances, joining segments with
+    /// a miter or a bevel for sharp turns
+    fn offset_vertices(
+        &self,
+        coords: &[Coordinate<f64>],
+        dist: f64,
+    ) -> Vec<Point<f64>> {
+        const MITER_LIMIT: f64 = 2.0;
 
-            if sum >= stop {
-                slice.push(interpolate(&p0, &p1, (stop - (sum - d)) / d));
-                return slice.into();
-            }
+        let mut vertices = coords.to_vec();
+        vertices.dedup();
+        if vertices.len() < 2 {
+            return vec![];
+        }
 
-            if sum > start {
-                slice.push(p1);
+        // unit normals to the left of each segment, in ruler units
+        let normals = vertices
+            .windows(2)
+            .map(|segment| {
+                let dx = long_diff(segment[1].x, segment[0].x) * self.kx;
+                let dy = (segment[1].y - segment[0].y) * self.ky;
+                let len = (dx * dx + dy * dy).sqrt();
+                (-dy / len, dx / len)
+            })
+            .collect::<Vec<(f64, f64)>>();
+
+        let last = normals.len() - 1;
+        let mut offset = vec![];
+        for (i, vertex) in vertices.iter().enumerate() {
+            let p = (*vertex).into();
+            let n1 = normals[i.saturating_sub(1)];
+            let n2 = normals[i.min(last)];
+
+            let (mx, my) = (n1.0 + n2.0, n1.1 + n2.1);
+            let m2 = mx * mx + my * my;
+            if m2 * MITER_LIMIT * MITER_LIMIT >= 4.0 {
+                let scale = 2.0 * dist / m2;
+                offset.push(self.offset(&p, mx * scale, my * scale));
+            } else {
+                offset.push(self.offset(&p, n1.0 * dist, n1.1 * dist));
+                offset.push(self.offset(&p, n2.0 * dist, n2.1 * dist));
             }
         }
+        offset
+    }
 
-        slice.into()
+    /// Splits a line into consecutive pieces of the given length, the last
+    /// piece being the possibly shorter remainder. Consecutive pieces share
+    /// the point the line was cut at. Lines of zero length give no pieces.
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - Line string
+    /// * `chunk_length` - Length of each piece, must be greater than 0
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_length` is not greater than 0.
+    pub fn chunk(
+        &self,
+        line: &LineString<f64>,
+        chunk_length: f64,
+    ) -> Vec<LineString<f64>> {
+        assert!(chunk_length > 0.0, "Chunk length must be positive");
+
+        let total = self.line_distance(line);
+        let mut chunks = vec![];
+        let mut i = 0.0;
+        while i * chunk_length < total {
+            let start = i * chunk_length;
+            let stop = (i + 1.0) * chunk_length;
+            chunks.push(self.line_slice_along(start, stop, line));
+            i += 1.0;
+        }
+        chunks
+    }
+
+    /// Returns the bounding rectangle of the exterior ring of the given
+    /// polygon, or `None` if the ring is empty
+    ///
+    /// The extent is computed naively from the minimum and maximum coordinates,
+    /// so polygons crossing the antimeridian get a rectangle spanning the whole
+    /// longitude range in between.
+    ///
+    /// # Arguments
+    ///
+    /// * `polygon` - Polygon
+    pub fn polygon_bbox(&self, polygon: &Polygon<f64>) -> Option<Rect<f64>> {
+        let mut coords = polygon.exterior().0.iter();
+        let first = *coords.next()?;
+        let (min, max) = coords.fold((first, first), |(min, max), c| {
+            (
+                Coordinate {
+                    x: min.x.min(c.x),
+                    y: min.y.min(c.y),
+                },
+                Coordinate {
+                    x: max.x.max(c.x),
+                    y: max.y.max(c.y),
+                },
+            )
+        });
+        Some(Rect::new(min, max))
+    }
+
+    /// Returns the bounding rectangle enclosing the exterior rings of all the
+    /// polygons, or `None` if all of them are empty
+    ///
+    /// Like `polygon_bbox`, antimeridian crossings are not handled.
+    ///
+    /// # Arguments
+    ///
+    /// * `polygons` - Multi polygon
+    pub fn multipolygon_bbox(
+        &self,
+        polygons: &MultiPolygon<f64>,
+    ) -> Option<Rect<f64>> {
+        polygons
+            .0
+            .iter()
+            .filter_map(|polygon| self.polygon_bbox(polygon))
+            .fold(None, |acc: Option<Rect<f64>>, bbox| {
+                Some(acc.map_or(bbox, |acc| {
+                    Rect::new(
+                        Coordinate {
+                            x: acc.min().x.min(bbox.min().x),
+                            y: acc.min().y.min(bbox.min().y),
+                        },
+                        Coordinate {
+                            x: acc.max().x.max(bbox.max().x),
+                            y: acc.max().y.max(bbox.max().y),
+                        },
+                    )
+                }))
+            })
     }
 
     /// Given a point, returns a bounding rectangle created from the given point
@@ -523,23 +2829,62 @@ impl CheapRuler {
 
     /// Given a bounding box, returns the box buffered by a given distance
     ///
+    /// A negative buffer contracts the box instead. If the box is contracted
+    /// by more than half its width or height, that dimension collapses to the
+    /// box's center rather than inverting its min and max bounds.
+    ///
     /// # Arguments
     ///
     /// * `bbox` - Bounding box
     /// * `buffer` - Buffer distance
     pub fn buffer_bbox(&self, bbox: &Rect<f64>, buffer: f64) -> Rect<f64> {
-        let v = buffer / self.ky;
-        let h = buffer / self.kx;
+        self.buffer_bbox_xy(bbox, buffer, buffer)
+    }
+
+    /// Given a bounding box, returns the box buffered by separate distances
+    /// horizontally (east-west) and vertically (north-south)
+    ///
+    /// Negative buffers contract the box, collapsing a dimension to the box's
+    /// center like `buffer_bbox` does.
+    ///
+    /// # Arguments
+    ///
+    /// * `bbox` - Bounding box
+    /// * `h_buffer` - Buffer distance applied to the longitude bounds
+    /// * `v_buffer` - Buffer distance applied to the latitude bounds
+    pub fn buffer_bbox_xy(
+        &self,
+        bbox: &Rect<f64>,
+        h_buffer: f64,
+        v_buffer: f64,
+    ) -> Rect<f64> {
+        let v = v_buffer / self.ky;
+        let h = h_buffer / self.kx;
+
+        // only boxes crossing the international date line wrap around it
+        let width = if bbox.min().x <= bbox.max().x {
+            bbox.max().x - bbox.min().x
+        } else {
+            bbox.max().x - bbox.min().x + 360.0
+        };
+        let height = bbox.max().y - bbox.min().y;
+
+        let (min_x, max_x) = if width + 2.0 * h < 0.0 {
+            let center = remainder(bbox.min().x + width / 2.0, 360.0);
+            (center, center)
+        } else {
+            (bbox.min().x - h, bbox.max().x + h)
+        };
+        let (min_y, max_y) = if height + 2.0 * v < 0.0 {
+            let center = bbox.min().y + height / 2.0;
+            (center, center)
+        } else {
+            (bbox.min().y - v, bbox.max().y + v)
+        };
 
         Rect::new(
-            Coordinate {
-                x: bbox.min().x - h,
-                y: bbox.min().y - v,
-            },
-            Coordinate {
-                x: bbox.max().x + h,
-                y: bbox.max().y + v,
-            },
+            Coordinate { x: min_x, y: min_y },
+            Coordinate { x: max_x, y: max_y },
         )
     }
 
@@ -556,6 +2901,451 @@ impl CheapRuler {
             && long_diff(p.lng(), bbox.min().x) >= 0.0
             && long_diff(p.lng(), bbox.max().x) <= 0.0
     }
+
+    /// Returns the given point moved to the nearest position inside the given
+    /// bounding box. Latitude is clamped directly, while longitude is clamped
+    /// to the nearer of the two edges, taking the international date line
+    /// into account. Points already inside the box are returned unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - Point
+    /// * `bbox` - Bounding box
+    pub fn clamp_to_bbox(
+        &self,
+        p: &Point<f64>,
+        bbox: &Rect<f64>,
+    ) -> Point<f64> {
+        let lat = p.lat().max(bbox.min().y).min(bbox.max().y);
+
+        let to_min = long_diff(p.lng(), bbox.min().x);
+        let to_max = long_diff(p.lng(), bbox.max().x);
+        let lng = if to_min >= 0.0 && to_max <= 0.0 {
+            p.lng()
+        } else if to_min.abs() <= to_max.abs() {
+            bbox.min().x
+        } else {
+            bbox.max().x
+        };
+
+        point!(x: lng, y: lat)
+    }
+
+    /// Returns the distance from the given point to the nearest point of the
+    /// given bounding box, or 0 if the point is inside the box. Takes the
+    /// international date line into account like `clamp_to_bbox`.
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - Point
+    /// * `bbox` - Bounding box
+    pub fn distance_to_bbox(&self, p: &Point<f64>, bbox: &Rect<f64>) -> f64 {
+        self.distance(p, &self.clamp_to_bbox(p, bbox))
+    }
+
+    /// Returns true if the given point is inside the given polygon, i.e.
+    /// inside its exterior ring and outside all of its interior rings,
+    /// otherwise false. Points on the boundary may be considered either inside
+    /// or outside.
+    ///
+    /// # Arguments
+    ///
+    /// * `polygon` - Polygon
+    /// * `p` - Point
+    pub fn contains_point(
+        &self,
+        polygon: &Polygon<f64>,
+        p: &Point<f64>,
+    ) -> bool {
+        let origin = p.0;
+        let ring_contains = |ring: &LineString<f64>| {
+            let mut inside = false;
+            for edge in ring.0.windows(2) {
+                let (ax, ay) = self.to_local(&origin, edge[0]);
+                let (bx, by) = self.to_local(&origin, edge[1]);
+                if (ay > 0.0) != (by > 0.0)
+                    && ax - ay * (bx - ax) / (by - ay) > 0.0
+                {
+                    inside = !inside;
+                }
+            }
+            inside
+        };
+
+        ring_contains(polygon.exterior())
+            && !polygon.interiors().iter().any(ring_contains)
+    }
+
+    /// Returns a tuple of the form (point, distance) where point is the closest
+    /// point on the boundary of the polygon, i.e. on any of its rings, and
+    /// distance is its distance from the given point. Returns `None` if the
+    /// polygon's exterior is empty.
+    ///
+    /// The closest boundary point is returned whether the given point is
+    /// inside the polygon or not.
+    ///
+    /// # Arguments
+    ///
+    /// * `polygon` - Polygon
+    /// * `p` - Point
+    pub fn closest_on_polygon(
+        &self,
+        polygon: &Polygon<f64>,
+        p: &Point<f64>,
+    ) -> Option<(Point<f64>, f64)> {
+        let first = polygon.exterior().0.first()?;
+        let mut closest = (*first).into();
+        let mut min_dist = self.square_distance(p, &closest);
+
+        for ring in iter::once(polygon.exterior()).chain(polygon.interiors()) {
+            for segment in ring.0.windows(2) {
+                let (a, b) = (segment[0].into(), segment[1].into());
+                let foot = self.segment_foot(p, &a, &b);
+                let d2 = self.square_distance(p, &foot);
+                if d2 < min_dist {
+                    min_dist = d2;
+                    closest = foot;
+                }
+            }
+        }
+
+        Some((closest, min_dist.sqrt()))
+    }
+
+    /// Tests whether each of the given points is inside the given polygon, like
+    /// `contains_point`, writing the results to `out` at the same indices. The
+    /// polygon's edges are only projected once for all the points, which makes
+    /// this much faster than calling `contains_point` for each of them.
+    ///
+    /// # Arguments
+    ///
+    /// * `polygon` - Polygon
+    /// * `points` - Points to test
+    /// * `out` - Results, of the same length as `points`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is not of the same length as `points`.
+    pub fn contains_points(
+        &self,
+        polygon: &Polygon<f64>,
+        points: &[Point<f64>],
+        out: &mut [bool],
+    ) {
+        assert_eq!(
+            points.len(),
+            out.len(),
+            "One result per point expected"
+        );
+
+        let origin = match polygon.exterior().0.first() {
+            Some(&origin) => origin,
+            None => {
+                out.iter_mut().for_each(|inside| *inside = false);
+                return;
+            }
+        };
+        let rings = iter::once(polygon.exterior())
+            .chain(polygon.interiors())
+            .map(|ring| {
+                ring.0
+                    .iter()
+                    .map(|c| self.to_local(&origin, *c))
+                    .collect::<Vec<(f64, f64)>>()
+            })
+            .collect::<Vec<Vec<(f64, f64)>>>();
+
+        for (p, inside) in points.iter().zip(out.iter_mut()) {
+            let (px, py) = self.to_local(&origin, p.0);
+            let ring_contains = |ring: &Vec<(f64, f64)>| {
+                let mut inside = false;
+                for edge in ring.windows(2) {
+                    let (ax, ay) = (edge[0].0 - px, edge[0].1 - py);
+                    let (bx, by) = (edge[1].0 - px, edge[1].1 - py);
+                    if (ay > 0.0) != (by > 0.0)
+                        && ax - ay * (bx - ax) / (by - ay) > 0.0
+                    {
+                        inside = !inside;
+                    }
+                }
+                inside
+            };
+            *inside = ring_contains(&rings[0])
+                && !rings[1..].iter().any(ring_contains);
+        }
+    }
+
+    /// Returns true if the given bounding box is entirely inside the given
+    /// polygon, otherwise false. All four corners of the box must be inside
+    /// the polygon, and no edge or vertex of the polygon may touch the box.
+    ///
+    /// The polygon's rings are assumed to be simple, i.e. not
+    /// self-intersecting.
+    ///
+    /// # Arguments
+    ///
+    /// * `bbox` - Bounding box
+    /// * `polygon` - Polygon
+    pub fn bbox_in_polygon(
+        &self,
+        bbox: &Rect<f64>,
+        polygon: &Polygon<f64>,
+    ) -> bool {
+        let (min, max) = (bbox.min(), bbox.max());
+        let corners = [
+            min,
+            Coordinate { x: max.x, y: min.y },
+            max,
+            Coordinate { x: min.x, y: max.y },
+        ];
+        if !corners
+            .iter()
+            .all(|c| self.contains_point(polygon, &(*c).into()))
+        {
+            return false;
+        }
+
+        let local_corners = corners
+            .iter()
+            .map(|c| self.to_local(&min, *c))
+            .collect::<Vec<(f64, f64)>>();
+        let (width, height) = local_corners[2];
+
+        iter::once(polygon.exterior())
+            .chain(polygon.interiors())
+            .all(|ring| {
+                let vertices = ring
+                    .0
+                    .iter()
+                    .map(|c| self.to_local(&min, *c))
+                    .collect::<Vec<(f64, f64)>>();
+                let vertex_inside = vertices.iter().any(|&(x, y)| {
+                    x > 0.0 && x < width && y > 0.0 && y < height
+                });
+                let edge_crosses = vertices.windows(2).any(|edge| {
+                    (0..4).any(|i| {
+                        segments_intersect(
+                            edge[0],
+                            edge[1],
+                            local_corners[i],
+                            local_corners[(i + 1) % 4],
+                        )
+                    })
+                });
+                !vertex_inside && !edge_crosses
+            })
+    }
+
+    /// Returns all the points where two lines cross or touch each other, in the
+    /// order they are found walking along line `a`. Crossings found more than
+    /// once, such as at a vertex shared by two segments, are only included
+    /// once. Where segments overlap, only one of their common points is
+    /// included.
+    ///
+    /// All segment pairs are compared, which is fine for city scale lines.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - First line
+    /// * `b` - Second line
+    pub fn line_intersections(
+        &self,
+        a: &LineString<f64>,
+        b: &LineString<f64>,
+    ) -> Vec<Point<f64>> {
+        let origin = match a.0.first() {
+            Some(&origin) => origin,
+            None => return vec![],
+        };
+        let local = |line: &LineString<f64>| {
+            line.0
+                .iter()
+                .map(|c| self.to_local(&origin, *c))
+                .collect::<Vec<(f64, f64)>>()
+        };
+        let (local_a, local_b) = (local(a), local(b));
+
+        let mut points: Vec<Point<f64>> = vec![];
+        for p in local_a.windows(2) {
+            for q in local_b.windows(2) {
+                let intersection = segment_intersection(p[0], p[1], q[0], q[1]);
+                if let Some((x, y)) = intersection {
+                    let point = self.offset(&origin.into(), x, y);
+                    let duplicate = points.iter().any(|other| {
+                        (other.lng() - point.lng()).abs() < 1e-12
+                            && (other.lat() - point.lat()).abs() < 1e-12
+                    });
+                    if !duplicate {
+                        points.push(point);
+                    }
+                }
+            }
+        }
+        points
+    }
+
+    /// Splits a line at the points where it crosses or touches itself,
+    /// returning pieces that don't intersect themselves, in order.
+    /// Consecutive pieces share the crossing point they were cut at. A line
+    /// without self-intersections is returned unchanged as the only element.
+    ///
+    /// The line is walked from its start, and each segment is cut where it
+    /// first meets the current piece. Only the segments of the current piece
+    /// are compared, which is fine for city scale lines.
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - Line to split
+    pub fn split_at_self_intersections(
+        &self,
+        line: &LineString<f64>,
+    ) -> Vec<LineString<f64>> {
+        let origin = match line.0.first() {
+            Some(&origin) => origin,
+            None => return vec![line.clone()],
+        };
+
+        let mut pieces: Vec<LineString<f64>> = vec![];
+        let mut piece = vec![origin];
+        let mut local_piece = vec![(0.0, 0.0)];
+        for &c in line.0.iter().skip(1) {
+            let start = local_piece[local_piece.len() - 1];
+            let end = self.to_local(&origin, c);
+            let len = (end.0 - start.0).hypot(end.1 - start.1);
+
+            // the last segment of the piece only shares its end with the new
+            // one, so it is skipped
+            let n = local_piece.len();
+            let cut = local_piece[..n - 1]
+                .windows(2)
+                .filter_map(|s| segment_intersection(s[0], s[1], start, end))
+                .map(|x| ((x.0 - start.0).hypot(x.1 - start.1), x))
+                .filter(|&(d, _)| d > 1e-9 * len)
+                .min_by(|a, b| {
+                    a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal)
+                });
+
+            match cut {
+                Some((d, x)) if d < len * (1.0 - 1e-9) => {
+                    let cut = self.offset(&origin.into(), x.0, x.1).0;
+                    piece.push(cut);
+                    pieces.push(mem::replace(&mut piece, vec![cut, c]).into());
+                    local_piece = vec![x, end];
+                }
+                Some(_) => {
+                    // the segment ends on the piece
+                    piece.push(c);
+                    pieces.push(mem::replace(&mut piece, vec![c]).into());
+                    local_piece = vec![end];
+                }
+                None => {
+                    piece.push(c);
+                    local_piece.push(end);
+                }
+            }
+        }
+        if piece.len() > 1 || pieces.is_empty() {
+            pieces.push(piece.into());
+        }
+        pieces
+    }
+
+    /// Projects a point onto the infinite line through the origin at the given
+    /// bearing, returning a tuple of the form (along, cross) in ruler units:
+    /// along is the distance from the origin in the direction of the bearing
+    /// (negative behind the origin), and cross the distance from the line,
+    /// positive to the left of the direction of travel and negative to the
+    /// right.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - Origin of the line
+    /// * `bearing` - Bearing of the line in degrees
+    /// * `p` - Point to project
+    pub fn along_cross_track(
+        &self,
+        origin: &Point<f64>,
+        bearing: f64,
+        p: &Point<f64>,
+    ) -> (f64, f64) {
+        let (x, y) = self.to_local(&origin.0, p.0);
+        let (sin, cos) = (bearing * RAD).sin_cos();
+        (x * sin + y * cos, y * sin - x * cos)
+    }
+
+    /// Creates a flat frame centered on the given origin, in which points can
+    /// be projected once to do distance and bearing math as plain Euclidean
+    /// operations in ruler units
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - Origin of the frame
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheap_ruler::{CheapRuler, DistanceUnit};
+    /// let cr = CheapRuler::new(44.7192003, DistanceUnit::Meters);
+    /// let frame = cr.local_frame(&(14.8901816, 44.7209699).into());
+    /// let a = frame.project(&(14.8901816, 44.7209699).into());
+    /// let b = frame.project(&(14.8905188, 44.7209699).into());
+    /// assert!(frame.distance(a, b) < 38.0);
+    /// ```
+    pub fn local_frame(&self, origin: &Point<f64>) -> LocalFrame {
+        LocalFrame::new(*origin, self.kx, self.ky)
+    }
+
+    /// Converts a coordinate into ruler units east and north of the origin
+    fn to_local(
+        &self,
+        origin: &Coordinate<f64>,
+        c: Coordinate<f64>,
+    ) -> (f64, f64) {
+        (
+            long_diff(c.x, origin.x) * self.kx,
+            (c.y - origin.y) * self.ky,
+        )
+    }
+}
+
+/// Creates a point from a longitude and a latitude, in that order. Points used
+/// throughout the crate are in the [x = longitude, y = latitude] form, the same
+/// order as GeoJSON positions.
+///
+/// # Arguments
+///
+/// * `lng` - Longitude
+/// * `lat` - Latitude
+///
+/// # Examples
+///
+/// ```
+/// use cheap_ruler::point_lng_lat;
+/// let p = point_lng_lat(14.8901816, 44.7209699);
+/// assert_eq!(p.x(), 14.8901816);
+/// ```
+pub fn point_lng_lat(lng: f64, lat: f64) -> Point<f64> {
+    point!(x: lng, y: lat)
+}
+
+/// Creates a point from a latitude and a longitude, in that order, as they are
+/// often written outside of GeoJSON. The resulting point is still in the
+/// crate's [x = longitude, y = latitude] form.
+///
+/// # Arguments
+///
+/// * `lat` - Latitude
+/// * `lng` - Longitude
+///
+/// # Examples
+///
+/// ```
+/// use cheap_ruler::point_lat_lng;
+/// let p = point_lat_lng(44.7209699, 14.8901816);
+/// assert_eq!(p.x(), 14.8901816);
+/// ```
+pub fn point_lat_lng(lat: f64, lng: f64) -> Point<f64> {
+    point!(x: lng, y: lat)
 }
 
 pub fn interpolate(a: &Point<f64>, b: &Point<f64>, t: f64) -> Point<f64> {
@@ -564,21 +3354,409 @@ pub fn interpolate(a: &Point<f64>, b: &Point<f64>, t: f64) -> Point<f64> {
     Point::new(a.lng() + dx * t, a.lat() + dy * t)
 }
 
+/// Returns the signed smallest difference between two bearings, i.e. the angle
+/// to turn from bearing `a` to reach bearing `b`, in the range -180 to 180
+///
+/// # Arguments
+///
+/// * `a` - First bearing
+/// * `b` - Second bearing
+///
+/// # Examples
+///
+/// ```
+/// use cheap_ruler::bearing_difference;
+/// assert_eq!(bearing_difference(350.0, 10.0), 20.0);
+/// assert_eq!(bearing_difference(10.0, 350.0), -20.0);
+/// ```
+pub fn bearing_difference(a: f64, b: f64) -> f64 {
+    remainder(b - a, 360.0)
+}
+
+/// Interpolates between two bearings along the shortest angular path, so that
+/// going from 350 to 10 passes through 0. The result is in the range -180 to
+/// 180, like the bearings returned by `CheapRuler::bearing`.
+///
+/// # Arguments
+///
+/// * `a` - First bearing
+/// * `b` - Second bearing
+/// * `t` - Parameter, 0 giving `a` and 1 giving `b`
+///
+/// # Examples
+///
+/// ```
+/// use cheap_ruler::interpolate_bearing;
+/// assert_eq!(interpolate_bearing(350.0, 10.0, 0.5), 0.0);
+/// ```
+pub fn interpolate_bearing(a: f64, b: f64, t: f64) -> f64 {
+    remainder(a + bearing_difference(a, b) * t, 360.0)
+}
+
+/// Returns the circular mean of the given bearings, in the range 0 to 360, or
+/// `None` if no bearings are given. Unlike the arithmetic mean, it is correct
+/// across the 0/360 seam, e.g. the mean of 350 and 10 is 0.
+///
+/// The mean is undefined for bearings whose unit vectors cancel each other
+/// out, like 0 and 180: the result is then derived from rounding noise in
+/// their sum, and is an arbitrary bearing that shouldn't be relied on.
+///
+/// # Arguments
+///
+/// * `bearings` - Bearings in degrees
+///
+/// # Examples
+///
+/// ```
+/// use cheap_ruler::{bearing_difference, circular_mean};
+/// let mean = circular_mean(&[350.0, 10.0]).unwrap();
+/// assert!(bearing_difference(0.0, mean).abs() < 1e-9);
+/// ```
+pub fn circular_mean(bearings: &[f64]) -> Option<f64> {
+    if bearings.is_empty() {
+        return None;
+    }
+    let (sin, cos) = bearings.iter().fold((0.0, 0.0), |(sin, cos), b| {
+        let (s, c) = (b * RAD).sin_cos();
+        (sin + s, cos + c)
+    });
+    let mean = sin.atan2(cos) / RAD;
+    if mean < 0.0 {
+        // tiny negative means would otherwise round to 360
+        Some((mean + 360.0) % 360.0)
+    } else {
+        Some(mean)
+    }
+}
+
+/// Converts an angle from degrees to radians, using the same `RAD` constant as
+/// the ruler
+///
+/// # Arguments
+///
+/// * `degrees` - Angle in degrees
+pub fn deg_to_rad(degrees: f64) -> f64 {
+    degrees * RAD
+}
+
+/// Converts an angle from radians to degrees, using the same `RAD` constant as
+/// the ruler
+///
+/// # Arguments
+///
+/// * `radians` - Angle in radians
+pub fn rad_to_deg(radians: f64) -> f64 {
+    radians / RAD
+}
+
+/// Estimates the error of measuring away from the ruler's latitude, by building
+/// a ruler at `reference_lat` and measuring an east-west displacement of
+/// `query_lng_delta` degrees at `query_lat`. Returns the relative error of the
+/// measured distance compared to the haversine great-circle distance.
+///
+/// Note that the ruler is based on the WGS84 ellipsoid whereas the haversine
+/// formula uses a sphere of the mean WGS84 radius, so even at the reference
+/// latitude the error is not zero, but a fraction of a percent.
+///
+/// # Arguments
+///
+/// * `reference_lat` - Latitude the ruler is created for
+/// * `query_lat` - Latitude of the measured displacement
+/// * `query_lng_delta` - Longitude difference of the measured displacement
+///
+/// # Examples
+///
+/// ```
+/// use cheap_ruler::relative_error_at;
+/// let near = relative_error_at(45.0, 45.1, 0.1);
+/// let far = relative_error_at(45.0, 50.0, 0.1);
+/// assert!(near < far);
+/// ```
+pub fn relative_error_at(
+    reference_lat: f64,
+    query_lat: f64,
+    query_lng_delta: f64,
+) -> f64 {
+    let ruler = CheapRuler::new(reference_lat, DistanceUnit::Kilometers);
+    let a = point!(x: 0.0, y: query_lat);
+    let b = point!(x: query_lng_delta, y: query_lat);
+
+    let expected = haversine(&a, &b);
+    if expected == 0.0 {
+        return 0.0;
+    }
+    ((ruler.distance(&a, &b) - expected) / expected).abs()
+}
+
+/// Returns the longitude/latitude bounding rectangle of the given Web Mercator
+/// (slippy map) tile
+///
+/// # Arguments
+///
+/// * `x` - x
+/// * `y` - y
+/// * `z` - z, less than 32
+///
+/// # Panics
+///
+/// Panics if `z` is not less than 32.
+///
+/// # Examples
+///
+/// ```
+/// use cheap_ruler::tile_bounds;
+/// let bbox = tile_bounds(0, 0, 0);
+/// assert_eq!(bbox.min().x, -180.0);
+/// assert_eq!(bbox.max().x, 180.0);
+/// ```
+pub fn tile_bounds(x: u32, y: u32, z: u32) -> Rect<f64> {
+    assert!(z < 32, "Zoom level must be less than 32");
+
+    let (x, y) = (x as f64, y as f64);
+    Rect::new(
+        (tile_lng(x, z), tile_lat(y + 1.0, z)),
+        (tile_lng(x + 1.0, z), tile_lat(y, z)),
+    )
+}
+
+/// Returns the longitude/latitude center point of the given Web Mercator
+/// (slippy map) tile. Its latitude is the one `CheapRuler::from_tile` uses.
+///
+/// # Arguments
+///
+/// * `x` - x
+/// * `y` - y
+/// * `z` - z, less than 32
+///
+/// # Panics
+///
+/// Panics if `z` is not less than 32.
+///
+/// # Examples
+///
+/// ```
+/// use cheap_ruler::tile_center;
+/// let center = tile_center(0, 0, 0);
+/// assert_eq!(center.x(), 0.0);
+/// assert_eq!(center.y(), 0.0);
+/// ```
+pub fn tile_center(x: u32, y: u32, z: u32) -> Point<f64> {
+    assert!(z < 32, "Zoom level must be less than 32");
+
+    point!(x: tile_lng(x as f64 + 0.5, z), y: tile_lat(y as f64 + 0.5, z))
+}
+
+/// Returns the longitude/latitude of a pixel within the given Web Mercator
+/// (slippy map) tile, with pixel coordinates measured from the top left
+/// corner of the tile
+///
+/// # Arguments
+///
+/// * `x` - x
+/// * `y` - y
+/// * `z` - z, less than 32
+/// * `px` - Pixel x, from 0 at the left edge to `tile_size` at the right one
+/// * `py` - Pixel y, from 0 at the top edge to `tile_size` at the bottom one
+/// * `tile_size` - Size of the tile in pixels, e.g. 256 or 512
+///
+/// # Panics
+///
+/// Panics if `z` is not less than 32.
+///
+/// # Examples
+///
+/// ```
+/// use cheap_ruler::tile_pixel_to_coord;
+/// let p = tile_pixel_to_coord(0, 0, 0, 128.0, 128.0, 256.0);
+/// assert_eq!(p.x(), 0.0);
+/// assert_eq!(p.y(), 0.0);
+/// ```
+pub fn tile_pixel_to_coord(
+    x: u32,
+    y: u32,
+    z: u32,
+    px: f64,
+    py: f64,
+    tile_size: f64,
+) -> Point<f64> {
+    assert!(z < 32, "Zoom level must be less than 32");
+
+    point!(
+        x: tile_lng(x as f64 + px / tile_size, z),
+        y: tile_lat(y as f64 + py / tile_size, z)
+    )
+}
+
+/// Returns the multipliers for converting longitude and latitude degrees into
+/// the given distance unit at the given latitude, as a (kx, ky) tuple. These
+/// are the same multipliers a `CheapRuler` created with `CheapRuler::new` uses,
+/// without creating the ruler.
+///
+/// # Arguments
+///
+/// * `latitude` - Latitude to compute the multipliers for
+/// * `distance_unit` - Unit to express distances in
+///
+/// # Examples
+///
+/// ```
+/// use cheap_ruler::{multipliers_for, DistanceUnit};
+/// let (kx, ky) = multipliers_for(44.7192003, DistanceUnit::Kilometers);
+/// assert!(kx < ky);
+/// ```
+pub fn multipliers_for(
+    latitude: f64,
+    distance_unit: DistanceUnit,
+) -> (f64, f64) {
+    let (dkx, dky) = curvature_multipliers(
+        latitude,
+        WGS84_EQUATORIAL_RADIUS,
+        WGS84_FLATTENING,
+    );
+    calculate_multipliers(distance_unit, dkx, dky)
+}
+
+/// Longitude of the given (possibly fractional) Web Mercator tile x
+fn tile_lng(x: f64, z: u32) -> f64 {
+    x / ((1u64 << z) as f64) * 360.0 - 180.0
+}
+
+/// Latitude of the given (possibly fractional) Web Mercator tile y
+fn tile_lat(y: f64, z: u32) -> f64 {
+    let n = f64::consts::PI * (1.0 - 2.0 * y / ((1u64 << z) as f64));
+    n.sinh().atan() / RAD
+}
+
+/// Multipliers for converting longitude and latitude degrees into kilometers
+fn curvature_multipliers(
+    latitude: f64,
+    equatorial_radius: f64,
+    flattening: f64,
+) -> (f64, f64) {
+    let e2 = flattening * (2.0 - flattening);
+
+    // Curvature formulas from https://en.wikipedia.org/wiki/Earth_radius#Meridional
+    let coslat = (latitude * RAD).cos();
+    let w2 = 1.0 / (1.0 - e2 * (1.0 - coslat * coslat));
+    let w = w2.sqrt();
+
+    let mul = RAD * equatorial_radius;
+    // based on normal and meridonal radius of curvature respectively
+    let dkx = mul * w * coslat;
+    let dky = mul * w * w2 * (1.0 - e2);
+    (dkx, dky)
+}
+
 fn calculate_multipliers(
     distance_unit: DistanceUnit,
     dkx: f64,
     dky: f64,
 ) -> (f64, f64) {
-    let mul = distance_unit.conversion_factor_kilometers() * RAD * RE;
+    let mul = distance_unit.conversion_factor_kilometers();
     let kx = mul * dkx;
     let ky = mul * dky;
     (kx, ky)
 }
 
+/// Great-circle distance in kilometers on a sphere with the mean WGS84 radius
+fn haversine(a: &Point<f64>, b: &Point<f64>) -> f64 {
+    let dlat = (b.lat() - a.lat()) * RAD;
+    let dlng = long_diff(b.lng(), a.lng()) * RAD;
+    let h = (dlat / 2.0).sin().powi(2)
+        + (a.lat() * RAD).cos()
+            * (b.lat() * RAD).cos()
+            * (dlng / 2.0).sin().powi(2);
+    2.0 * RM * h.sqrt().asin()
+}
+
+fn join_coords(
+    mut head: Vec<Coordinate<f64>>,
+    tail: Vec<Coordinate<f64>>,
+) -> Vec<Coordinate<f64>> {
+    let skip = (head.last() == tail.first()) as usize;
+    head.extend(tail.into_iter().skip(skip));
+    head
+}
+
 fn long_diff(a: f64, b: f64) -> f64 {
     remainder(a - b, 360.0)
 }
 
+/// Returns true if the segments p1-p2 and q1-q2 intersect or touch
+fn segments_intersect(
+    p1: (f64, f64),
+    p2: (f64, f64),
+    q1: (f64, f64),
+    q2: (f64, f64),
+) -> bool {
+    let cross = |o: (f64, f64), a: (f64, f64), b: (f64, f64)| {
+        (a.0 - o.0) * (b.1 - o.1) - (a.1 - o.1) * (b.0 - o.0)
+    };
+    let on_segment = |a: (f64, f64), b: (f64, f64), p: (f64, f64)| {
+        p.0 >= a.0.min(b.0)
+            && p.0 <= a.0.max(b.0)
+            && p.1 >= a.1.min(b.1)
+            && p.1 <= a.1.max(b.1)
+    };
+
+    let d1 = cross(q1, q2, p1);
+    let d2 = cross(q1, q2, p2);
+    let d3 = cross(p1, p2, q1);
+    let d4 = cross(p1, p2, q2);
+
+    if ((d1 > 0.0 && d2 < 0.0) || (d1 < 0.0 && d2 > 0.0))
+        && ((d3 > 0.0 && d4 < 0.0) || (d3 < 0.0 && d4 > 0.0))
+    {
+        return true;
+    }
+
+    (d1 == 0.0 && on_segment(q1, q2, p1))
+        || (d2 == 0.0 && on_segment(q1, q2, p2))
+        || (d3 == 0.0 && on_segment(p1, p2, q1))
+        || (d4 == 0.0 && on_segment(p1, p2, q2))
+}
+
+/// Returns the point where the segments p1-p2 and q1-q2 intersect or touch.
+/// For collinear overlapping segments, returns one of their common endpoints.
+fn segment_intersection(
+    p1: (f64, f64),
+    p2: (f64, f64),
+    q1: (f64, f64),
+    q2: (f64, f64),
+) -> Option<(f64, f64)> {
+    let cross = |a: (f64, f64), b: (f64, f64)| a.0 * b.1 - a.1 * b.0;
+    let r = (p2.0 - p1.0, p2.1 - p1.1);
+    let s = (q2.0 - q1.0, q2.1 - q1.1);
+    let pq = (q1.0 - p1.0, q1.1 - p1.1);
+    let denom = cross(r, s);
+
+    if denom == 0.0 {
+        if !segments_intersect(p1, p2, q1, q2) {
+            return None;
+        }
+        // collinear: an endpoint within the extent of both segments lies on
+        // both of them
+        let within = |a: (f64, f64), b: (f64, f64), p: (f64, f64)| {
+            p.0 >= a.0.min(b.0)
+                && p.0 <= a.0.max(b.0)
+                && p.1 >= a.1.min(b.1)
+                && p.1 <= a.1.max(b.1)
+        };
+        return [p1, p2, q1, q2]
+            .iter()
+            .copied()
+            .find(|&c| within(p1, p2, c) && within(q1, q2, c));
+    }
+
+    let t = cross(pq, s) / denom;
+    let u = cross(pq, r) / denom;
+    if (0.0..=1.0).contains(&t) && (0.0..=1.0).contains(&u) {
+        Some((p1.0 + t * r.0, p1.1 + t * r.1))
+    } else {
+        None
+    }
+}
+
 fn sum_area(line: &[Point<f64>]) -> f64 {
     let line_len = line.len();
     let mut sum = 0.0;
@@ -591,6 +3769,40 @@ fn sum_area(line: &[Point<f64>]) -> f64 {
     sum
 }
 
+/// Signed area of an open ring of local coordinates, positive for
+/// counter-clockwise rings
+fn shoelace(ring: &[(f64, f64)]) -> f64 {
+    let n = ring.len();
+    (0..n)
+        .map(|i| {
+            let (p, q) = (ring[i], ring[(i + 1) % n]);
+            p.0 * q.1 - q.0 * p.1
+        })
+        .sum::<f64>()
+        / 2.0
+}
+
+fn spherical_excess(ring: &[Coordinate<f64>]) -> f64 {
+    // sum of the signed excesses of the quadrilaterals bounded by each edge,
+    // the equator and the meridians through the edge's endpoints
+    ring.windows(2)
+        .map(|edge| {
+            let dlng = long_diff(edge[1].x, edge[0].x) * RAD;
+            let t1 = (edge[0].y * RAD / 2.0).tan();
+            let t2 = (edge[1].y * RAD / 2.0).tan();
+            2.0 * ((dlng / 2.0).tan() * (t1 + t2) / (1.0 + t1 * t2)).atan()
+        })
+        .sum()
+}
+
+mod benchmark_case;
+mod distance;
 mod distance_unit;
+mod length_accumulator;
+mod local_frame;
+mod matcher;
+mod measurement;
+mod orientation;
 mod point_on_line;
 mod rect;
+mod side;
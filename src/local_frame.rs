@@ -0,0 +1,72 @@
+use crate::{long_diff, RAD};
+use geo_types::Point;
+
+/// A flat frame centered on an origin point, in which coordinates are
+/// expressed in ruler units east and north of the origin. Projecting points
+/// once and then doing distance and bearing math as plain Euclidean operations
+/// is faster for heavy inner loops, and gives the same results as the ruler
+/// the frame was created from.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct LocalFrame {
+    origin: Point<f64>,
+    kx: f64,
+    ky: f64,
+}
+
+impl LocalFrame {
+    pub(crate) fn new(origin: Point<f64>, kx: f64, ky: f64) -> Self {
+        Self { origin, kx, ky }
+    }
+
+    /// Gets the origin point of the frame
+    pub fn origin(&self) -> Point<f64> {
+        self.origin
+    }
+
+    /// Projects a point into the frame, as (easting, northing) ruler units
+    /// from the origin
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - Point to project
+    pub fn project(&self, p: &Point<f64>) -> (f64, f64) {
+        (
+            long_diff(p.x(), self.origin.x()) * self.kx,
+            (p.y() - self.origin.y()) * self.ky,
+        )
+    }
+
+    /// Converts projected coordinates back into a point
+    ///
+    /// # Arguments
+    ///
+    /// * `xy` - (easting, northing) ruler units from the origin
+    pub fn unproject(&self, xy: (f64, f64)) -> Point<f64> {
+        let (x, y) = xy;
+        Point::new(
+            self.origin.x() + x / self.kx,
+            self.origin.y() + y / self.ky,
+        )
+    }
+
+    /// Returns the distance between two projected points, in ruler units
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - First projected point
+    /// * `b` - Second projected point
+    pub fn distance(&self, a: (f64, f64), b: (f64, f64)) -> f64 {
+        (b.0 - a.0).hypot(b.1 - a.1)
+    }
+
+    /// Returns the bearing from one projected point to another, in degrees
+    /// from -180 to 180 like `CheapRuler::bearing`
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - First projected point
+    /// * `b` - Second projected point
+    pub fn bearing(&self, a: (f64, f64), b: (f64, f64)) -> f64 {
+        (b.0 - a.0).atan2(b.1 - a.1) / RAD
+    }
+}
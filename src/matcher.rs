@@ -0,0 +1,87 @@
+use crate::{CheapRuler, PointOnLine};
+use geo_types::{LineString, Point};
+
+/// Snaps a sequence of points, such as GPS fixes, to a line, remembering the
+/// last matched segment so that each snap only searches a bounded number of
+/// segments around it instead of the whole line
+#[derive(Debug, PartialEq, Clone)]
+pub struct Matcher {
+    ruler: CheapRuler,
+    line: LineString<f64>,
+    window: usize,
+    last_index: Option<usize>,
+}
+
+impl Matcher {
+    /// Creates a matcher snapping to the given line
+    ///
+    /// # Arguments
+    ///
+    /// * `ruler` - Ruler used to find the closest points
+    /// * `line` - Line to snap to
+    /// * `window` - Number of segments to search behind and ahead of the last
+    ///   matched one
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheap_ruler::{CheapRuler, DistanceUnit, Matcher};
+    /// use geo_types::LineString;
+    /// let cr = CheapRuler::new(50.458, DistanceUnit::Meters);
+    /// let line_string: LineString<f64> = vec![
+    ///     (-67.031, 50.458),
+    ///     (-67.031, 50.534),
+    ///     (-66.929, 50.534),
+    /// ].into();
+    /// let mut matcher = Matcher::new(cr, line_string, 2);
+    /// let first = matcher.snap(&(-67.03, 50.46).into()).unwrap();
+    /// let second = matcher.snap(&(-67.0, 50.533).into()).unwrap();
+    /// assert_eq!(first.index(), 0);
+    /// assert_eq!(second.index(), 1);
+    /// ```
+    pub fn new(
+        ruler: CheapRuler,
+        line: LineString<f64>,
+        window: usize,
+    ) -> Self {
+        Self {
+            ruler,
+            line,
+            window,
+            last_index: None,
+        }
+    }
+
+    /// Returns the closest point on the line from the given point, like
+    /// `CheapRuler::point_on_line`. The first snap searches the whole line,
+    /// and the following ones only the segments within the window of the last
+    /// matched segment (see `CheapRuler::point_on_line_range`).
+    ///
+    /// # Arguments
+    ///
+    /// * `point` - Point to snap to the line
+    pub fn snap(&mut self, point: &Point<f64>) -> Option<PointOnLine<f64>> {
+        let pol = match self.last_index {
+            Some(i) => self.ruler.point_on_line_range(
+                &self.line,
+                point,
+                i.saturating_sub(self.window),
+                i.saturating_add(self.window),
+            ),
+            None => self.ruler.point_on_line(&self.line, point),
+        }?;
+        self.last_index = Some(pol.index());
+        Some(pol)
+    }
+
+    /// Gets the start index of the last matched segment, if any
+    pub fn last_index(&self) -> Option<usize> {
+        self.last_index
+    }
+
+    /// Forgets the last matched segment, so that the next snap searches the
+    /// whole line again
+    pub fn reset(&mut self) {
+        self.last_index = None;
+    }
+}
@@ -0,0 +1,50 @@
+/// Measured extent of a geometry, as returned by `CheapRuler::measure`
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Measurement {
+    /// Points and multi-points, which have no length or area
+    Zero,
+    /// Length of lines and multi-lines
+    Length(f64),
+    /// Area of polygons, multi-polygons, rectangles and triangles
+    Area(f64),
+    /// Geometry collection containing both linear and areal geometries
+    Mixed { length: f64, area: f64 },
+}
+
+impl Measurement {
+    /// Combines two measurements, summing lengths with lengths and areas with
+    /// areas
+    pub(crate) fn combine(self, other: Self) -> Self {
+        let (length, area) = self.parts();
+        let (other_length, other_area) = other.parts();
+        Self::from_parts(
+            add_parts(length, other_length),
+            add_parts(area, other_area),
+        )
+    }
+
+    fn parts(self) -> (Option<f64>, Option<f64>) {
+        match self {
+            Measurement::Zero => (None, None),
+            Measurement::Length(length) => (Some(length), None),
+            Measurement::Area(area) => (None, Some(area)),
+            Measurement::Mixed { length, area } => (Some(length), Some(area)),
+        }
+    }
+
+    fn from_parts(length: Option<f64>, area: Option<f64>) -> Self {
+        match (length, area) {
+            (None, None) => Measurement::Zero,
+            (Some(length), None) => Measurement::Length(length),
+            (None, Some(area)) => Measurement::Area(area),
+            (Some(length), Some(area)) => Measurement::Mixed { length, area },
+        }
+    }
+}
+
+fn add_parts(a: Option<f64>, b: Option<f64>) -> Option<f64> {
+    match (a, b) {
+        (None, None) => None,
+        _ => Some(a.unwrap_or(0.0) + b.unwrap_or(0.0)),
+    }
+}
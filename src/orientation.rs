@@ -0,0 +1,6 @@
+/// Winding order of a polygon ring
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Orientation {
+    Clockwise,
+    CounterClockwise,
+}
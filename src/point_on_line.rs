@@ -10,14 +10,20 @@ where
     point: Point<T>,
     index: usize,
     t: T,
+    dist: T,
 }
 
 impl<T> PointOnLine<T>
 where
     T: Num + NumCast + Copy + PartialEq + PartialOrd + Debug,
 {
-    pub fn new(point: Point<T>, index: usize, t: T) -> Self {
-        Self { point, index, t }
+    pub fn new(point: Point<T>, index: usize, t: T, dist: T) -> Self {
+        Self {
+            point,
+            index,
+            t,
+            dist,
+        }
     }
 
     pub fn point(&self) -> Point<T> {
@@ -31,4 +37,69 @@ where
     pub fn t(&self) -> T {
         self.t
     }
+
+    /// Gets the distance from the query point to this point, in ruler units.
+    /// This is how far the query point was from the line, not the distance
+    /// along the line.
+    pub fn dist(&self) -> T {
+        self.dist
+    }
+
+    /// Returns true if the point landed exactly on a vertex of the line, i.e.
+    /// `t` is exactly 0 or 1, otherwise false. Floats are compared for exact
+    /// equality, so a projection merely very close to a vertex is not
+    /// considered to be on it.
+    pub fn is_at_vertex(&self) -> bool {
+        self.t == T::zero() || self.t == T::one()
+    }
+
+    /// Interpolates a per-vertex attribute of the line at this point, blending
+    /// `values[index]` and `values[index + 1]` by `t` with the given function.
+    /// Returns `None` if `values` doesn't have an entry for both vertices of
+    /// the segment.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - Attribute values, one per vertex of the line
+    /// * `f` - Blend function, given the two values and `t`
+    pub fn interpolate_attribute<V>(
+        &self,
+        values: &[V],
+        f: impl Fn(&V, &V, T) -> V,
+    ) -> Option<V> {
+        let a = values.get(self.index)?;
+        let b = values.get(self.index + 1)?;
+        Some(f(a, b, self.t))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_is_at_vertex() {
+        let p = Point::new(30.0, 38.0);
+
+        assert!(PointOnLine::new(p, 0, 0.0, 0.0).is_at_vertex());
+        assert!(PointOnLine::new(p, 3, 1.0, 0.0).is_at_vertex());
+        assert!(!PointOnLine::new(p, 0, 0.5, 0.0).is_at_vertex());
+        assert!(!PointOnLine::new(p, 0, 1e-12, 0.0).is_at_vertex());
+    }
+
+    #[test]
+    fn test_interpolate_attribute() {
+        let p = Point::new(30.0, 38.0);
+        let speeds = [50.0, 70.0, 90.0];
+        let lerp = |a: &f64, b: &f64, t: f64| a + (b - a) * t;
+
+        let pol = PointOnLine::new(p, 1, 0.25, 0.0);
+        assert_eq!(Some(75.0), pol.interpolate_attribute(&speeds, lerp));
+
+        let pol = PointOnLine::new(p, 0, 0.0, 0.0);
+        assert_eq!(Some(50.0), pol.interpolate_attribute(&speeds, lerp));
+
+        let pol = PointOnLine::new(p, 2, 0.5, 0.0);
+        assert_eq!(None, pol.interpolate_attribute(&speeds, lerp));
+    }
 }
@@ -1,4 +1,4 @@
-use geo_types::{Coordinate, CoordinateType};
+use geo_types::{Coordinate, CoordinateType, LineString, Polygon};
 use std::borrow::Borrow;
 
 #[derive(Eq, PartialEq, Clone, Copy, Debug, Hash)]
@@ -27,6 +27,126 @@ impl<T: CoordinateType> Rect<T> {
     pub fn max(self) -> Coordinate<T> {
         self.max
     }
+
+    /// Returns the rectangle as a polygon with a closed 5-point exterior ring
+    /// going counter-clockwise from the min corner
+    pub fn to_polygon(&self) -> Polygon<T> {
+        let (min, max) = (self.min, self.max);
+        let ring = LineString(vec![
+            min,
+            Coordinate { x: max.x, y: min.y },
+            max,
+            Coordinate { x: min.x, y: max.y },
+            min,
+        ]);
+        Polygon::new(ring, vec![])
+    }
+}
+
+impl Rect<f64> {
+    /// Splits the rectangle at its center into four equal rectangles, in the
+    /// order south-west, south-east, north-west, north-east
+    ///
+    /// # Panics
+    ///
+    /// Panics if the rectangle spans the antimeridian, with a min x greater
+    /// than its max x.
+    pub fn quadrants(&self) -> [Rect<f64>; 4] {
+        assert!(
+            self.min.x <= self.max.x,
+            "Rectangles spanning the antimeridian can't be split"
+        );
+
+        let (min, max) = (self.min, self.max);
+        let center = Coordinate {
+            x: (min.x + max.x) / 2.0,
+            y: (min.y + max.y) / 2.0,
+        };
+        [
+            Rect::new(min, center),
+            Rect::new((center.x, min.y), (max.x, center.y)),
+            Rect::new((min.x, center.y), (center.x, max.y)),
+            Rect::new(center, max),
+        ]
+    }
+
+    /// Returns the overlapping part of two rectangles, or `None` if they don't
+    /// overlap. Rectangles touching along an edge or at a corner overlap, and
+    /// give a rectangle with zero width or height.
+    ///
+    /// Rectangles spanning the antimeridian, with a min x greater than their
+    /// max x, are split at it to be intersected, and the overlapping part
+    /// spans it too if both halves overlap. Such rectangles can also overlap
+    /// in two separate parts, e.g. the one from 170 to -170 with the one from
+    /// -175 to 175, which can't be represented by a single rectangle and
+    /// return `None` as well.
+    pub fn intersection(&self, other: &Rect<f64>) -> Option<Rect<f64>> {
+        if self.min.x <= self.max.x && other.min.x <= other.max.x {
+            return self.intersection_within_bounds(other);
+        }
+
+        let mut parts = vec![];
+        for a in self.split_at_antimeridian().iter() {
+            for b in other.split_at_antimeridian().iter() {
+                parts.extend(a.intersection_within_bounds(b));
+            }
+        }
+        match parts.as_slice() {
+            [part] => Some(*part),
+            [a, b] if a.max.x == 180.0 && b.min.x == -180.0 => {
+                Some(Rect::new(a.min, b.max))
+            }
+            [a, b] if b.max.x == 180.0 && a.min.x == -180.0 => {
+                Some(Rect::new(b.min, a.max))
+            }
+            _ => None,
+        }
+    }
+
+    /// Splits a rectangle spanning the antimeridian into its eastern and
+    /// western parts, other rectangles being returned as is
+    fn split_at_antimeridian(&self) -> Vec<Rect<f64>> {
+        if self.min.x <= self.max.x {
+            vec![*self]
+        } else {
+            vec![
+                Rect::new(
+                    self.min,
+                    Coordinate {
+                        x: 180.0,
+                        y: self.max.y,
+                    },
+                ),
+                Rect::new(
+                    Coordinate {
+                        x: -180.0,
+                        y: self.min.y,
+                    },
+                    self.max,
+                ),
+            ]
+        }
+    }
+
+    /// Intersection of two rectangles not spanning the antimeridian
+    fn intersection_within_bounds(
+        &self,
+        other: &Rect<f64>,
+    ) -> Option<Rect<f64>> {
+        let min = Coordinate {
+            x: self.min.x.max(other.min.x),
+            y: self.min.y.max(other.min.y),
+        };
+        let max = Coordinate {
+            x: self.max.x.min(other.max.x),
+            y: self.max.y.min(other.max.y),
+        };
+        if min.x <= max.x && min.y <= max.y {
+            Some(Rect::new(min, max))
+        } else {
+            None
+        }
+    }
 }
 
 impl<C, G> From<G> for Rect<C>
@@ -79,4 +199,99 @@ mod test {
         assert_eq!(bbox_rect_date_line.max().x, min_x);
         assert_eq!(bbox_rect_date_line.max().y, max_y);
     }
+
+    #[test]
+    fn test_rect_to_polygon() {
+        let rect = Rect::new((30.0, 38.0), (40.0, 39.0));
+        let polygon = rect.to_polygon();
+
+        assert_eq!(
+            polygon.exterior().0,
+            vec![
+                Coordinate { x: 30.0, y: 38.0 },
+                Coordinate { x: 40.0, y: 38.0 },
+                Coordinate { x: 40.0, y: 39.0 },
+                Coordinate { x: 30.0, y: 39.0 },
+                Coordinate { x: 30.0, y: 38.0 },
+            ]
+        );
+        assert!(polygon.interiors().is_empty());
+    }
+
+    #[test]
+    fn test_rect_quadrants() {
+        let rect = Rect::new((30.0, 38.0), (40.0, 39.0));
+        let [sw, se, nw, ne] = rect.quadrants();
+
+        assert_eq!(sw, Rect::new((30.0, 38.0), (35.0, 38.5)));
+        assert_eq!(se, Rect::new((35.0, 38.0), (40.0, 38.5)));
+        assert_eq!(nw, Rect::new((30.0, 38.5), (35.0, 39.0)));
+        assert_eq!(ne, Rect::new((35.0, 38.5), (40.0, 39.0)));
+    }
+
+    #[test]
+    #[should_panic(expected = "spanning the antimeridian")]
+    fn test_rect_quadrants_over_dateline() {
+        Rect::new((179.9, 32.7), (-179.9, 32.9)).quadrants();
+    }
+
+    #[test]
+    fn test_rect_intersection() {
+        let rect = Rect::new((30.0, 38.0), (40.0, 39.0));
+
+        assert_eq!(Some(rect), rect.intersection(&rect));
+        assert_eq!(
+            Some(Rect::new((35.0, 38.5), (40.0, 39.0))),
+            rect.intersection(&Rect::new((35.0, 38.5), (45.0, 40.0)))
+        );
+        let inner = Rect::new((31.0, 38.2), (32.0, 38.4));
+        assert_eq!(Some(inner), rect.intersection(&inner));
+        assert_eq!(Some(inner), inner.intersection(&rect));
+
+        // touching edges give a zero-width rectangle
+        assert_eq!(
+            Some(Rect::new((40.0, 38.0), (40.0, 39.0))),
+            rect.intersection(&Rect::new((40.0, 37.0), (41.0, 40.0)))
+        );
+
+        assert_eq!(
+            None,
+            rect.intersection(&Rect::new((41.0, 38.0), (42.0, 39.0)))
+        );
+        assert_eq!(
+            None,
+            rect.intersection(&Rect::new((30.0, 39.5), (40.0, 40.0)))
+        );
+    }
+
+    #[test]
+    fn test_rect_intersection_over_dateline() {
+        let crossing = Rect::new((170.0, 32.0), (-170.0, 33.0));
+
+        assert_eq!(Some(crossing), crossing.intersection(&crossing));
+        assert_eq!(
+            Some(Rect::new((175.0, 32.5), (-175.0, 33.0))),
+            crossing.intersection(&Rect::new((175.0, 32.5), (-175.0, 34.0)))
+        );
+        assert_eq!(
+            Some(crossing),
+            crossing.intersection(&Rect::new((-180.0, 30.0), (180.0, 40.0)))
+        );
+
+        let east = Rect::new((175.0, 32.0), (180.0, 33.0));
+        assert_eq!(Some(east), crossing.intersection(&east));
+        assert_eq!(Some(east), east.intersection(&crossing));
+        let west = Rect::new((-180.0, 32.5), (-171.0, 33.0));
+        assert_eq!(Some(west), west.intersection(&crossing));
+
+        assert_eq!(
+            None,
+            crossing.intersection(&Rect::new((10.0, 32.0), (20.0, 33.0)))
+        );
+        // overlapping in two separate parts
+        assert_eq!(
+            None,
+            crossing.intersection(&Rect::new((-175.0, 32.0), (175.0, 33.0)))
+        );
+    }
 }
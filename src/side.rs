@@ -0,0 +1,8 @@
+/// Side of a directed line that a point lies on, looking in the direction of
+/// travel
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Side {
+    Left,
+    Right,
+    On,
+}
@@ -1,3 +1,5 @@
+#![allow(deprecated)]
+
 extern crate cheap_ruler;
 #[macro_use]
 extern crate geo_types;
@@ -8,11 +10,301 @@ extern crate lazy_static;
 mod common;
 mod fixtures;
 
-use cheap_ruler::{CheapRuler, DistanceUnit, Rect};
-pub use common::*;
+use cheap_ruler::{
+    bearing_difference,
+    benchmark_suite,
+    CheapRuler,
+    circular_mean,
+    deg_to_rad,
+    DistanceUnit,
+    interpolate_bearing,
+    LengthAccumulator,
+    Matcher,
+    Measurement,
+    multipliers_for,
+    Orientation,
+    point_lat_lng,
+    point_lng_lat,
+    RAD,
+    rad_to_deg,
+    Rect,
+    relative_error_at,
+    Side,
+    tile_bounds,
+    tile_center,
+    tile_pixel_to_coord,
+    WGS84_EQUATORIAL_RADIUS,
+    WGS84_FLATTENING,
+};
 use fixtures::lines::{LINES, POINTS};
 use fixtures::turf;
-use geo_types::{Coordinate, LineString, Polygon};
+use geo_types::{
+    Coordinate,
+    Geometry,
+    GeometryCollection,
+    LineString,
+    MultiLineString,
+    MultiPoint,
+    MultiPolygon,
+    Point,
+    Polygon,
+};
+
+#[test]
+fn test_with_ellipsoid_wgs84() {
+    let ruler = fixtures::ruler_km();
+    let wgs84 = CheapRuler::with_ellipsoid(
+        32.8351,
+        DistanceUnit::Kilometers,
+        WGS84_EQUATORIAL_RADIUS,
+        WGS84_FLATTENING,
+    );
+
+    assert_eq!(ruler, wgs84);
+}
+
+#[test]
+fn test_with_ellipsoid_sphere() {
+    let radius = 6371.0;
+    let ruler =
+        CheapRuler::with_ellipsoid(0.0, DistanceUnit::Kilometers, radius, 0.0);
+
+    let p1 = point!(x: 0.0, y: 0.0);
+    let p2 = point!(x: 1.0, y: 0.0);
+    let p3 = point!(x: 0.0, y: 1.0);
+    let expected = radius * std::f64::consts::PI / 180.0;
+
+    assert_eq_err!(expected, ruler.distance(&p1, &p2), 1e-12);
+    assert_eq_err!(expected, ruler.distance(&p1, &p3), 1e-12);
+}
+
+#[test]
+fn test_relative_error_at() {
+    let at_reference = relative_error_at(32.8351, 32.8351, 0.01);
+    let near = relative_error_at(32.8351, 33.0, 0.01);
+    let far = relative_error_at(32.8351, 40.0, 0.01);
+
+    assert!(at_reference < 0.005);
+    assert!(at_reference < near);
+    assert!(near < far);
+    assert!(far > 0.05);
+    assert_eq!(0.0, relative_error_at(32.8351, 40.0, 0.0));
+}
+
+#[test]
+fn test_multipliers_for() {
+    let ruler = fixtures::ruler_miles();
+    let (kx, ky) = multipliers_for(32.8351, DistanceUnit::Miles);
+    let origin = point!(x: -96.9, y: 32.8351);
+
+    assert_eq_err!(
+        kx,
+        ruler.distance(&origin, &point!(x: -95.9, y: 32.8351)),
+        1e-12
+    );
+    assert_eq_err!(
+        ky,
+        ruler.distance(&origin, &point!(x: -96.9, y: 33.8351)),
+        1e-12
+    );
+}
+
+#[test]
+fn test_tile_bounds() {
+    let world = tile_bounds(0, 0, 0);
+    assert_eq_err!(-180.0, world.min().x, 1e-12);
+    assert_eq_err!(180.0, world.max().x, 1e-12);
+    assert_eq_err!(-85.0511287798066, world.min().y, 1e-12);
+    assert_eq_err!(85.0511287798066, world.max().y, 1e-12);
+
+    let bbox = tile_bounds(1, 0, 1);
+    assert_eq_err!(0.0, bbox.min().x, 1e-12);
+    assert_eq_err!(180.0, bbox.max().x, 1e-12);
+    assert_eq_err!(0.0, bbox.min().y, 1e-12);
+    assert_eq_err!(85.0511287798066, bbox.max().y, 1e-12);
+
+    // the latitude of a tile ruler is within the tile bounds
+    let bbox = tile_bounds(1205, 1567, 12);
+    let ruler = CheapRuler::from_tile(1567, 12, DistanceUnit::Miles);
+    assert!(bbox.min().y < ruler.latitude());
+    assert!(ruler.latitude() < bbox.max().y);
+}
+
+#[test]
+#[should_panic(expected = "Zoom level must be less than 32")]
+fn test_tile_bounds_zoom_too_large() {
+    tile_bounds(0, 0, 32);
+}
+
+#[test]
+fn test_tile_center() {
+    let center = tile_center(1205, 1567, 12);
+    let bbox = tile_bounds(1205, 1567, 12);
+    let ruler = CheapRuler::from_tile(1567, 12, DistanceUnit::Miles);
+
+    assert_eq!(ruler.latitude(), center.lat());
+    assert_eq_err!((bbox.min().x + bbox.max().x) / 2.0, center.lng(), 1e-12);
+    assert!(bbox.min().y < center.lat() && center.lat() < bbox.max().y);
+}
+
+#[test]
+#[should_panic(expected = "Zoom level must be less than 32")]
+fn test_tile_center_zoom_too_large() {
+    tile_center(0, 0, 32);
+}
+
+#[test]
+fn test_tile_pixel_to_coord() {
+    let top_left = tile_pixel_to_coord(0, 0, 1, 0.0, 0.0, 256.0);
+    assert_eq_err!(-180.0, top_left.lng(), 1e-12);
+    assert_eq_err!(85.0511287798066, top_left.lat(), 1e-12);
+
+    let center = tile_pixel_to_coord(0, 0, 1, 512.0, 512.0, 512.0);
+    assert_eq_err!(0.0, center.lng(), 1e-12);
+    assert_eq_err!(0.0, center.lat(), 1e-12);
+
+    let bbox = tile_bounds(1205, 1567, 12);
+    let nw = tile_pixel_to_coord(1205, 1567, 12, 0.0, 0.0, 256.0);
+    let se = tile_pixel_to_coord(1205, 1567, 12, 256.0, 256.0, 256.0);
+    assert_eq_err!(bbox.min().x, nw.lng(), 1e-12);
+    assert_eq_err!(bbox.max().y, nw.lat(), 1e-12);
+    assert_eq_err!(bbox.max().x, se.lng(), 1e-12);
+    assert_eq_err!(bbox.min().y, se.lat(), 1e-12);
+    assert_eq!(se, tile_pixel_to_coord(1206, 1568, 12, 0.0, 0.0, 256.0));
+
+    assert_eq!(
+        tile_center(1205, 1567, 12),
+        tile_pixel_to_coord(1205, 1567, 12, 128.0, 128.0, 256.0)
+    );
+}
+
+#[test]
+#[should_panic(expected = "Zoom level must be less than 32")]
+fn test_tile_pixel_to_coord_zoom_too_large() {
+    tile_pixel_to_coord(0, 0, 32, 0.0, 0.0, 256.0);
+}
+
+#[test]
+fn test_benchmark_suite() {
+    let suite = benchmark_suite();
+    assert_eq!(100, suite.len());
+
+    for case in suite.iter() {
+        assert!(!case.name().is_empty());
+        assert_eq!(case.latitude(), case.origin().lat());
+
+        let ruler = CheapRuler::new(case.latitude(), case.distance().unit());
+        let dist = case.distance().value();
+        let (origin, bearing) = (case.origin(), case.bearing());
+        let expected = ruler.great_circle_destination(&origin, dist, bearing);
+        let actual = ruler.destination(&origin, dist, bearing);
+        // the ruler stays close to the great circle destination at city
+        // scale, drifting further at larger distances
+        let max_error = if dist <= 10.0 { 0.01 } else { 0.05 };
+        assert!(ruler.distance(&expected, &actual) <= dist * max_error);
+    }
+}
+
+#[test]
+fn test_parts() {
+    let ruler = fixtures::ruler_miles();
+
+    assert_eq!(32.8351, ruler.latitude());
+    assert_eq!((32.8351, DistanceUnit::Miles), ruler.to_parts());
+    assert_eq!(ruler, CheapRuler::from_parts(ruler.to_parts()));
+
+    let tile_ruler = CheapRuler::from_tile(11041, 15, DistanceUnit::Meters);
+    assert_eq!(tile_ruler, CheapRuler::from_parts(tile_ruler.to_parts()));
+
+    let changed = ruler.clone_with_unit(DistanceUnit::Feet);
+    assert_eq!((32.8351, DistanceUnit::Feet), changed.to_parts());
+}
+
+#[test]
+fn test_approx_eq() {
+    let ruler = fixtures::ruler_km();
+    let close = CheapRuler::new(32.8352, DistanceUnit::Kilometers);
+    let far = CheapRuler::new(33.8351, DistanceUnit::Kilometers);
+
+    assert!(ruler.approx_eq(&ruler, 0.0));
+    assert!(ruler != close);
+    assert!(ruler.approx_eq(&close, 1e-3));
+    assert!(!ruler.approx_eq(&close, 1e-9));
+    assert!(!ruler.approx_eq(&far, 1e-3));
+    let meters = ruler.clone_with_unit(DistanceUnit::Meters);
+    assert!(!ruler.approx_eq(&meters, f64::INFINITY));
+}
+
+#[test]
+fn test_unit_conversions() {
+    let meters_per_unit = [
+        (DistanceUnit::Kilometers, 1000.0),
+        (DistanceUnit::Miles, 1609.344),
+        (DistanceUnit::NauticalMiles, 1852.0),
+        (DistanceUnit::Meters, 1.0),
+        (DistanceUnit::Yards, 0.9144),
+        (DistanceUnit::Feet, 0.3048),
+        (DistanceUnit::Inches, 0.0254),
+        (DistanceUnit::Custom { km_per_unit: 0.08 }, 80.0),
+    ];
+    let ruler_m = CheapRuler::new(32.8351, DistanceUnit::Meters);
+    let (a, b) = (&POINTS[0], &POINTS[1]);
+    let meters = ruler_m.distance(a, b);
+    let corner = |dx: f64, dy: f64| ruler_m.offset(a, dx, dy);
+    let triangle = Polygon::new(
+        LineString::from(vec![
+            corner(0.0, 0.0),
+            corner(1000.0, 0.0),
+            corner(0.0, 1000.0),
+        ]),
+        vec![],
+    );
+    let square_meters = ruler_m.area(&triangle);
+    assert_eq_err!(500000.0, square_meters, 1e-9);
+
+    for &(unit, factor) in meters_per_unit.iter() {
+        let ruler = ruler_m.clone_with_unit(unit);
+
+        assert_eq_err!(meters, ruler.distance(a, b) * factor, 1e-12);
+        assert_eq_err!(
+            square_meters,
+            ruler.area(&triangle) * factor * factor,
+            1e-12
+        );
+
+        let d = ruler.distance_typed(a, b);
+        for &(other, other_factor) in meters_per_unit.iter() {
+            let converted = d.to_unit(other);
+            assert_eq_err!(meters, converted.value() * other_factor, 1e-12);
+            assert_eq_err!(d.value(), converted.to_unit(unit).value(), 1e-12);
+        }
+
+        assert_eq!(ruler_m, ruler.clone_with_unit(DistanceUnit::Meters));
+    }
+}
+
+#[test]
+fn test_custom_unit() {
+    let blocks = DistanceUnit::Custom { km_per_unit: 0.08 };
+    let ruler = fixtures::ruler_km();
+    let ruler_blocks = CheapRuler::new(32.8351, blocks);
+    let (a, b) = (&POINTS[0], &POINTS[1]);
+
+    assert_eq_err!(
+        ruler.distance(a, b) / 0.08,
+        ruler_blocks.distance(a, b),
+        1e-12
+    );
+    assert_eq!(ruler_blocks, ruler.clone_with_unit(blocks));
+
+    let mut changed = ruler_blocks.clone();
+    changed.change_unit(DistanceUnit::Kilometers);
+    assert_eq!(ruler, changed);
+
+    assert_eq!(blocks, DistanceUnit::Custom { km_per_unit: 0.08 });
+    assert!(blocks != DistanceUnit::Custom { km_per_unit: 0.1 });
+    assert!(blocks != DistanceUnit::Kilometers);
+}
 
 #[test]
 fn test_change_unit() {
@@ -62,6 +354,180 @@ fn test_distance() {
     }
 }
 
+#[test]
+fn test_speed() {
+    let ruler = fixtures::ruler_km();
+    let (a, b) = (&POINTS[0], &POINTS[1]);
+
+    assert_eq_err!(ruler.distance(a, b) / 60.0, ruler.speed(a, b, 60.0), 1e-12);
+    assert_eq!(0.0, ruler.speed(a, a, 1.0));
+}
+
+#[test]
+fn test_speed_zero_time() {
+    let ruler = fixtures::ruler_km();
+
+    assert_eq!(0.0, ruler.speed(&POINTS[0], &POINTS[1], 0.0));
+    assert_eq!(0.0, ruler.speed(&POINTS[0], &POINTS[1], -1.0));
+}
+
+#[test]
+fn test_closest_approach() {
+    let ruler = fixtures::ruler_km();
+    let p1 = point!(x: 30.5, y: 32.8351);
+    let p2 = ruler.offset(&p1, 10.0, 2.0);
+
+    // heading towards each other east-west, passing 2 km apart
+    let (time, dist) =
+        ruler.closest_approach(&p1, (0.1, 0.0), &p2, (-0.1, 0.0));
+    assert_eq_err!(50.0, time, 1e-9);
+    assert_eq_err!(2.0, dist, 1e-9);
+
+    // moving apart
+    let (time, dist) =
+        ruler.closest_approach(&p1, (-0.1, 0.0), &p2, (0.1, 0.0));
+    assert_eq!(0.0, time);
+    assert_eq_err!(ruler.distance(&p1, &p2), dist, 1e-12);
+
+    // same velocity
+    let (time, dist) =
+        ruler.closest_approach(&p1, (0.1, 0.2), &p2, (0.1, 0.2));
+    assert_eq!(0.0, time);
+    assert_eq_err!(ruler.distance(&p1, &p2), dist, 1e-12);
+
+    // collision
+    let (time, dist) =
+        ruler.closest_approach(&p1, (0.0, 0.0), &p2, (-1.0, -0.2));
+    assert_eq_err!(10.0, time, 1e-9);
+    assert!(dist < 1e-9);
+}
+
+#[test]
+fn test_within() {
+    let ruler = fixtures::ruler_km();
+    let a = point!(x: -67.031, y: 50.458);
+    let b = point!(x: -66.929, y: 50.534);
+    let dist = ruler.distance(&a, &b);
+
+    assert!(ruler.within(&a, &b, dist + 1e-9));
+    assert!(!ruler.within(&a, &b, dist - 1e-9));
+    assert!(ruler.within(&a, &a, 0.0));
+
+    // across the antimeridian
+    let c = point!(x: 179.999, y: 32.8351);
+    let d = point!(x: -179.999, y: 32.8351);
+    assert!(ruler.within(&c, &d, ruler.distance(&c, &d) + 1e-9));
+    assert!(ruler.within(&c, &d, 1.0));
+}
+
+#[test]
+fn test_distance_matrix() {
+    let ruler = fixtures::ruler_km();
+    let points = &POINTS[..10];
+    let n = points.len();
+
+    let matrix = ruler.distance_matrix(points);
+    assert_eq!(n * n, matrix.len());
+    for i in 0..n {
+        assert_eq!(0.0, matrix[i * n + i]);
+        for j in 0..n {
+            assert_eq!(matrix[i * n + j], matrix[j * n + i]);
+            if i != j {
+                assert_eq_err!(
+                    ruler.distance(&points[i], &points[j]),
+                    matrix[i * n + j],
+                    1e-12
+                );
+            }
+        }
+    }
+
+    assert!(ruler.distance_matrix(&[]).is_empty());
+}
+
+#[test]
+fn test_with_unit_check() {
+    let ruler = fixtures::ruler_km();
+    let dist = ruler.distance(&POINTS[0], &POINTS[1]);
+
+    assert_eq!(dist, ruler.with_unit_check(dist));
+    assert_eq!(20000.0, ruler.with_unit_check(20000.0));
+
+    let ruler_m = ruler.clone_with_unit(DistanceUnit::Meters);
+    assert_eq!(20000.0 * 1000.0, ruler_m.with_unit_check(20000.0 * 1000.0));
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "too large to be in Kilometers")]
+fn test_with_unit_check_meters_given_to_km() {
+    let ruler = fixtures::ruler_km();
+    ruler.with_unit_check(25000.0);
+}
+
+#[test]
+fn test_distance_xy() {
+    let ruler = fixtures::ruler_km();
+
+    for i in 0..POINTS.len() - 1 {
+        let (a, b) = (POINTS[i], POINTS[i + 1]);
+        let expected = ruler.distance(&a, &b);
+        let actual = ruler.distance_xy(a.x_y(), b.x_y());
+
+        assert_eq!(expected, actual);
+    }
+}
+
+#[test]
+fn test_distance_typed() {
+    let ruler = fixtures::ruler_km();
+    let ruler_miles = fixtures::ruler_miles();
+
+    let p1 = point!(x: 30.5, y: 32.8351);
+    let p2 = point!(x: 30.51, y: 32.8451);
+
+    let d = ruler.distance_typed(&p1, &p2);
+    let d2 = ruler_miles.distance_typed(&p1, &p2);
+
+    assert_eq!(ruler.distance(&p1, &p2), d.value());
+    assert_eq!(DistanceUnit::Kilometers, d.unit());
+    assert_eq!(DistanceUnit::Miles, d2.unit());
+
+    assert_eq_err!(d.value() * 2.0, (d + d).value(), 1e-12);
+    assert_eq_err!(0.0, (d - d).value(), 1e-12);
+    assert!(d < d + d);
+    assert!(d.partial_cmp(&d2).is_none());
+
+    let converted = d2.to_unit(DistanceUnit::Kilometers);
+    assert_eq!(DistanceUnit::Kilometers, converted.unit());
+    assert_eq_err!(d.value(), converted.value(), 1e-12);
+    assert_eq_err!(d.value(), (converted + d).value() / 2.0, 1e-12);
+}
+
+#[test]
+#[should_panic(expected = "Distances expressed in different units")]
+fn test_distance_typed_mixed_units() {
+    let ruler = fixtures::ruler_km();
+    let ruler_miles = fixtures::ruler_miles();
+
+    let p1 = point!(x: 30.5, y: 32.8351);
+    let p2 = point!(x: 30.51, y: 32.8451);
+
+    let d = ruler.distance_typed(&p1, &p2);
+    let d2 = ruler_miles.distance_typed(&p1, &p2);
+
+    let _ = d + d2;
+}
+
+#[test]
+fn test_point_lng_lat() {
+    let p = point_lng_lat(30.5, 32.8351);
+    let q = point_lat_lng(32.8351, 30.5);
+
+    assert_eq!(point!(x: 30.5, y: 32.8351), p);
+    assert_eq!(p, q);
+}
+
 #[test]
 fn test_distance_over_dateline() {
     let ruler = fixtures::ruler_km();
@@ -101,133 +567,1729 @@ fn test_bearing() {
 }
 
 #[test]
-fn test_destination() {
+fn test_bearing_to() {
     let ruler = fixtures::ruler_km();
+    let origin = point!(x: 30.5, y: 32.8351);
+    let directions = [
+        (0.0, 1.0, 0.0),
+        (1.0, 1.0, 45.0),
+        (1.0, 0.0, 90.0),
+        (1.0, -1.0, 135.0),
+        (0.0, -1.0, 180.0),
+        (-1.0, -1.0, 225.0),
+        (-1.0, 0.0, 270.0),
+        (-1.0, 1.0, 315.0),
+    ];
 
-    for i in 0..POINTS.len() {
-        let bearing = (i % 360) as f64 - 180.0;
-        let expected = turf::DESTINATION[i];
+    for &(dx, dy, expected) in directions.iter() {
+        let to = ruler.offset(&origin, dx, dy);
+        let actual = ruler.bearing_to(&origin, &to);
+
+        assert!((0.0..360.0).contains(&actual));
+        assert_eq_err!(expected, actual, 1e-9);
+        assert_eq_err!(
+            0.0,
+            bearing_difference(ruler.bearing(&origin, &to), actual),
+            1e-9
+        );
+    }
 
-        let actual = ruler.destination(&POINTS[i], 1.0, bearing);
+    assert_eq!(0.0, ruler.bearing_to(&origin, &origin));
+}
 
-        assert_eq_err!(expected.lng(), actual.lng(), 1e-6); // longitude
-        assert_eq_err!(expected.lat(), actual.lat(), 1e-6); // latitude
+#[test]
+fn test_line_bearing_and_length() {
+    let ruler = fixtures::ruler_km();
+
+    for line in LINES.iter() {
+        for seg in line.lines() {
+            let (a, b) = (seg.start_point(), seg.end_point());
+            assert_eq!(ruler.bearing(&a, &b), ruler.line_bearing(&seg));
+            assert_eq!(ruler.distance(&a, &b), ruler.line_length(&seg));
+        }
     }
 }
 
 #[test]
-fn test_line_distance_empty() {
-    let ruler = fixtures::ruler_km();
+fn test_bearing_difference() {
+    assert_eq_err!(20.0, bearing_difference(350.0, 10.0), 1e-12);
+    assert_eq_err!(-20.0, bearing_difference(10.0, 350.0), 1e-12);
+    assert_eq_err!(20.0, bearing_difference(-10.0, 10.0), 1e-12);
+    assert_eq_err!(-90.0, bearing_difference(45.0, -45.0), 1e-12);
+    assert_eq_err!(10.0, bearing_difference(170.0, -180.0), 1e-12);
+    assert_eq_err!(0.0, bearing_difference(0.0, 360.0), 1e-12);
+    assert_eq_err!(180.0, bearing_difference(0.0, 180.0).abs(), 1e-12);
+}
 
-    let empty_line = line_string![];
-    let expected = 0.0;
-    let actual = ruler.line_distance(&empty_line);
+#[test]
+fn test_interpolate_bearing() {
+    // crossing the seam forward and backward
+    assert_eq_err!(0.0, interpolate_bearing(350.0, 10.0, 0.5), 1e-12);
+    assert_eq_err!(-5.0, interpolate_bearing(350.0, 10.0, 0.25), 1e-12);
+    assert_eq_err!(5.0, interpolate_bearing(10.0, 350.0, 0.25), 1e-12);
+    assert_eq_err!(-10.0, interpolate_bearing(10.0, 350.0, 1.0), 1e-12);
+
+    // crossing the -180 / 180 seam
+    assert_eq_err!(180.0, interpolate_bearing(170.0, -170.0, 0.5).abs(), 1e-12);
+    assert_eq_err!(-175.0, interpolate_bearing(170.0, -170.0, 0.75), 1e-12);
+    assert_eq_err!(175.0, interpolate_bearing(-170.0, 170.0, 0.75), 1e-12);
+
+    assert_eq_err!(45.0, interpolate_bearing(0.0, 90.0, 0.5), 1e-12);
+    assert_eq_err!(30.0, interpolate_bearing(30.0, 90.0, 0.0), 1e-12);
+}
 
-    assert_eq_err!(expected, actual, 0.0);
+#[test]
+fn test_circular_mean() {
+    let near_north = |mean: f64| bearing_difference(0.0, mean).abs() < 1e-9;
+
+    assert!(near_north(circular_mean(&[350.0, 10.0]).unwrap()));
+    assert!(near_north(circular_mean(&[-10.0, 10.0, 0.0]).unwrap()));
+    assert_eq_err!(45.0, circular_mean(&[0.0, 90.0]).unwrap(), 1e-12);
+    assert_eq_err!(270.0, circular_mean(&[-90.0]).unwrap(), 1e-12);
+    assert_eq_err!(180.0, circular_mean(&[170.0, -170.0]).unwrap(), 1e-12);
+    assert_eq!(None, circular_mean(&[]));
+
+    let tiny = circular_mean(&[-1e-15]).unwrap();
+    assert!((0.0..360.0).contains(&tiny));
 }
 
 #[test]
-fn test_line_distance() {
+fn test_deg_to_rad() {
+    assert_eq!(RAD, deg_to_rad(1.0));
+    assert_eq_err!(std::f64::consts::PI, deg_to_rad(180.0), 1e-15);
+    assert_eq_err!(-90.0, rad_to_deg(-std::f64::consts::FRAC_PI_2), 1e-15);
+    assert_eq_err!(123.456, rad_to_deg(deg_to_rad(123.456)), 1e-15);
+
     let ruler = fixtures::ruler_km();
+    let p = point!(x: -96.9, y: 32.8351);
+    assert_eq!(
+        ruler.destination(&p, 1.0, 30.0),
+        ruler.destination_rad(&p, 1.0, deg_to_rad(30.0))
+    );
+}
 
-    for i in 0..LINES.len() {
-        let expected = turf::LINEDISTANCE[i];
-        let actual = ruler.line_distance(&LINES[i]);
+#[test]
+fn test_reverse_bearing() {
+    let ruler = fixtures::ruler_km();
 
-        assert_eq_err!(expected, actual, 0.003);
-    }
+    assert_eq_err!(180.0, ruler.reverse_bearing(0.0).abs(), 1e-12);
+    assert_eq_err!(-90.0, ruler.reverse_bearing(90.0), 1e-12);
+    assert_eq_err!(0.0, ruler.reverse_bearing(180.0), 1e-12);
+    assert_eq_err!(0.0, ruler.reverse_bearing(-180.0), 1e-12);
+    assert_eq_err!(90.0, ruler.reverse_bearing(-90.0), 1e-12);
+    assert_eq_err!(-135.0, ruler.reverse_bearing(45.0), 1e-12);
+
+    let a = point!(x: 30.5, y: 32.8351);
+    let b = point!(x: 30.51, y: 32.8451);
+    assert_eq_err!(
+        ruler.bearing(&b, &a),
+        ruler.reverse_bearing(ruler.bearing(&a, &b)),
+        1e-12
+    );
 }
 
 #[test]
-fn test_area() {
+fn test_mirror_point() {
     let ruler = fixtures::ruler_km();
 
-    let mut j = 0;
-    for i in 0..LINES.len() {
-        if LINES[i].points_iter().count() < 3 {
-            continue;
-        }
+    let origin = point!(x: 30.5, y: 32.8351);
+    let p = point!(x: 30.51, y: 32.8451);
+    let mirrored = ruler.mirror_point(&origin, &p);
 
-        let mut ring = LINES[i].to_owned().into_points();
-        ring.push(point!(x: LINES[i][0].x, y: LINES[i][0].y));
+    assert_eq_err!(30.49, mirrored.lng(), 1e-12);
+    assert_eq_err!(32.8251, mirrored.lat(), 1e-12);
+    assert_eq_err!(
+        ruler.distance(&origin, &p),
+        ruler.distance(&origin, &mirrored),
+        1e-9
+    );
 
-        let polygon = Polygon::new(LineString::from(ring), vec![]);
-        let expected = turf::AREA[j];
-        let actual = ruler.area(&polygon);
+    let over_dateline = ruler.mirror_point(
+        &point!(x: 180.0, y: 32.8),
+        &point!(x: -179.9, y: 32.8),
+    );
+    assert_eq_err!(179.9, over_dateline.lng(), 1e-12);
+}
 
-        assert_eq_err!(expected, actual, 0.003);
+#[test]
+fn test_segment_angle() {
+    let ruler = fixtures::ruler_km();
 
-        j += 1;
-    }
+    let a1 = point!(x: 30.5, y: 32.8351);
+    let north = ruler.destination(&a1, 1.0, 0.0);
+    let b1 = point!(x: 30.6, y: 32.9);
+    let east = ruler.destination(&b1, 1.0, 90.0);
+    let west = ruler.destination(&b1, 1.0, -90.0);
+    let south = ruler.destination(&b1, 1.0, 180.0);
+
+    assert_eq_err!(90.0, ruler.segment_angle(&a1, &north, &b1, &east), 1e-9);
+    assert_eq_err!(-90.0, ruler.segment_angle(&a1, &north, &b1, &west), 1e-9);
+    assert_eq_err!(
+        180.0,
+        ruler.segment_angle(&a1, &north, &b1, &south).abs(),
+        1e-9
+    );
+    assert_eq_err!(
+        180.0,
+        ruler.segment_angle(&b1, &east, &b1, &west).abs(),
+        1e-9
+    );
+    assert_eq_err!(0.0, ruler.segment_angle(&a1, &north, &a1, &north), 1e-9);
 }
 
 #[test]
-fn test_along() {
+fn test_destination() {
     let ruler = fixtures::ruler_km();
 
-    for i in 0..LINES.len() {
-        let expected = turf::ALONG[i];
-        let actual = ruler
-            .along(&LINES[i], turf::ALONG_DIST[i])
-            .expect("Non-empty line string given");
+    for i in 0..POINTS.len() {
+        let bearing = (i % 360) as f64 - 180.0;
+        let expected = turf::DESTINATION[i];
 
-        assert_eq_err!(expected.lng(), actual.lng(), 1e-6); // along longitude
-        assert_eq_err!(expected.lat(), actual.lat(), 1e-6); // along latitude
+        let actual = ruler.destination(&POINTS[i], 1.0, bearing);
+
+        assert_eq_err!(expected.lng(), actual.lng(), 1e-6); // longitude
+        assert_eq_err!(expected.lat(), actual.lat(), 1e-6); // latitude
     }
 }
 
 #[test]
-fn test_along_empty() {
+fn test_equidistant_ring() {
     let ruler = fixtures::ruler_km();
 
-    let empty_line = line_string![];
-    let actual = ruler.along(&empty_line, 0.0);
+    let origin = point!(x: 30.5, y: 32.8351);
+    let ring = ruler.equidistant_ring(&origin, 2.0, 32);
+    let exterior = ring.exterior();
 
-    assert!(actual.is_none());
+    assert_eq!(33, exterior.num_coords());
+    assert_eq!(exterior[0], exterior[32]);
+    for p in exterior.points_iter() {
+        assert_eq_err!(2.0, ruler.distance(&origin, &p), 1e-12);
+    }
 }
 
 #[test]
-fn test_along_with_dist_lt_0() {
+#[should_panic(expected = "At least 3 steps expected")]
+fn test_equidistant_ring_too_few_steps() {
     let ruler = fixtures::ruler_km();
 
-    let coord = LINES[0][0];
-    let actual = ruler
-        .along(&LINES[0], -5.0)
-        .expect("Non-empty line string given");
-
-    assert_eq!(point!(x: coord.x, y: coord.y), actual);
+    ruler.equidistant_ring(&point!(x: 30.5, y: 32.8351), 2.0, 2);
 }
 
 #[test]
-fn test_along_with_dist_greater_than_length() {
+fn test_destination_rad() {
     let ruler = fixtures::ruler_km();
 
-    let coord = LINES[0].points_iter().last().expect("Last element");
-    let actual = ruler
-        .along(&LINES[0], 1000.0)
-        .expect("Non-empty line string given");
+    for i in 0..POINTS.len() {
+        let bearing = (i % 360) as f64 - 180.0;
+        let expected = ruler.destination(&POINTS[i], 1.0, bearing);
+        let actual =
+            ruler.destination_rad(&POINTS[i], 1.0, bearing.to_radians());
 
-    assert_eq!(point!(x: coord.lng(), y: coord.lat()), actual);
+        assert_eq_err!(expected.lng(), actual.lng(), 1e-12);
+        assert_eq_err!(expected.lat(), actual.lat(), 1e-12);
+    }
 }
 
 #[test]
-fn test_point_on_line() {
+fn test_split_at_self_intersections() {
     let ruler = fixtures::ruler_km();
-
-    // not Turf comparison because pointOnLine is bugged https://github.com/Turfjs/turf/issues/344
     let line = line_string![
-        (x: -77.031669, y: 38.878605),
-        (x: -77.029609, y: 38.881946),
+        (x: -96.9, y: 32.83),
+        (x: -96.88, y: 32.85),
+        (x: -96.88, y: 32.83),
+        (x: -96.9, y: 32.85),
+        (x: -96.91, y: 32.85),
     ];
-    let point = point!(x: -77.034076, y: 38.882017);
-    let result = ruler
-        .point_on_line(&line, &point)
-        .expect("Non-empty line string given");
 
-    assert_eq_err!(-77.03052689033436, result.point().lng(), 1e-6);
-    assert_eq_err!(38.880457324462576, result.point().lat(), 1e-6);
-    assert_eq!(0, result.index()); // index
+    let pieces = ruler.split_at_self_intersections(&line);
+    assert_eq!(2, pieces.len());
+    assert_eq!(4, pieces[0].0.len());
+    assert_eq!(3, pieces[1].0.len());
+    assert_eq!(line.0[..3], pieces[0].0[..3]);
+    assert_eq!(pieces[0].0[3], pieces[1].0[0]);
+    assert_eq_err!(-96.89, pieces[1].0[0].x, 1e-12);
+    assert_eq_err!(32.84, pieces[1].0[0].y, 1e-12);
+    assert_eq!(line.0[3..], pieces[1].0[1..]);
+    assert_eq_err!(
+        ruler.line_distance(&line),
+        pieces.iter().map(|p| ruler.line_distance(p)).sum::<f64>(),
+        1e-12
+    );
+    for piece in pieces.iter() {
+        assert_eq!(1, ruler.split_at_self_intersections(piece).len());
+    }
+
+    // a line ending on itself is not split
+    let closed = line_string![
+        (x: -96.9, y: 32.83),
+        (x: -96.88, y: 32.83),
+        (x: -96.88, y: 32.85),
+        (x: -96.89, y: 32.83),
+    ];
+    let pieces = ruler.split_at_self_intersections(&closed);
+    assert_eq!(1, pieces.len());
+    assert_eq!(closed, pieces[0]);
+
+    let simple = &LINES[0];
+    let pieces = ruler.split_at_self_intersections(simple);
+    assert_eq!(vec![simple.clone()], pieces);
+
+    // non-finite coordinates don't panic
+    let mut invalid = line.clone();
+    invalid.0[2].x = f64::NAN;
+    ruler.split_at_self_intersections(&invalid);
+}
+
+#[test]
+fn test_along_cross_track() {
+    let ruler = fixtures::ruler_km();
+    let origin = point!(x: -96.9, y: 32.8351);
+
+    let ahead = ruler.destination(&origin, 3.0, 30.0);
+    let (along, cross) = ruler.along_cross_track(&origin, 30.0, &ahead);
+    assert_eq_err!(3.0, along, 1e-12);
+    assert!(cross.abs() < 1e-12);
+
+    // one unit along the left normal of the heading
+    let (sin, cos) = (30.0 * RAD).sin_cos();
+    let left = ruler.offset(&ahead, -cos, sin);
+    let (along, cross) = ruler.along_cross_track(&origin, 30.0, &left);
+    assert_eq_err!(3.0, along, 1e-12);
+    assert_eq_err!(1.0, cross, 1e-12);
+
+    // due east, seen from a northbound heading: right, abeam the origin
+    let east = ruler.offset(&origin, 2.0, 0.0);
+    let (along, cross) = ruler.along_cross_track(&origin, 0.0, &east);
+    assert!(along.abs() < 1e-12);
+    assert_eq_err!(-2.0, cross, 1e-12);
+
+    let behind = ruler.destination(&origin, 1.0, 210.0);
+    let (along, _) = ruler.along_cross_track(&origin, 30.0, &behind);
+    assert_eq_err!(-1.0, along, 1e-12);
+}
+
+#[test]
+fn test_great_circle_destination() {
+    let ruler = fixtures::ruler_km();
+    let origin = point!(x: -96.9, y: 32.8351);
+
+    for bearing in (0..360).step_by(15) {
+        let bearing = bearing as f64;
+        let expected = ruler.destination(&origin, 10.0, bearing);
+        let actual = ruler.great_circle_destination(&origin, 10.0, bearing);
+        assert!(ruler.distance(&expected, &actual) < 10.0 * 0.005);
+    }
+
+    let ruler_m = ruler.clone_with_unit(DistanceUnit::Meters);
+    let p = ruler.great_circle_destination(&origin, 10.0, 45.0);
+    let p_m = ruler_m.great_circle_destination(&origin, 10000.0, 45.0);
+    assert_eq_err!(p.lng(), p_m.lng(), 1e-12);
+    assert_eq_err!(p.lat(), p_m.lat(), 1e-12);
+
+    // a quarter of the way around the globe from the equator
+    let ruler = CheapRuler::new(0.0, DistanceUnit::Kilometers);
+    let quarter = std::f64::consts::PI * 6371.0088 / 2.0;
+    let origin = point!(x: 0.0, y: 0.0);
+    let p = ruler.great_circle_destination(&origin, quarter, 90.0);
+    assert_eq_err!(90.0, p.lng(), 1e-9);
+    assert!(p.lat().abs() < 1e-9);
+}
+
+#[test]
+fn test_roundtrip_residual() {
+    let ruler = fixtures::ruler_km();
+
+    for i in 0..POINTS.len() {
+        let bearing = (i as f64 * 37.0) % 360.0 - 180.0;
+        let (dist_err, bearing_err) =
+            ruler.roundtrip_residual(&POINTS[i], 1.0, bearing);
+        assert!(dist_err < 1e-9);
+        assert!(bearing_err < 1e-9);
+    }
+}
+
+#[test]
+fn test_move_towards() {
+    let ruler = fixtures::ruler_km();
+    let from = point!(x: -67.031, y: 50.458);
+    let to = point!(x: -66.929, y: 50.534);
+    let dist = ruler.distance(&from, &to);
+
+    let p = ruler.move_towards(&from, &to, 1.0);
+    assert_eq_err!(1.0, ruler.distance(&from, &p), 1e-9);
+    assert_eq_err!(dist - 1.0, ruler.distance(&p, &to), 1e-9);
+
+    assert_eq!(to, ruler.move_towards(&from, &to, dist));
+    assert_eq!(to, ruler.move_towards(&from, &to, dist + 1.0));
+    assert_eq!(from, ruler.move_towards(&from, &from, 1.0));
+}
+
+#[test]
+fn test_round_to() {
+    let ruler = fixtures::ruler_km();
+    let p = point!(x: -67.0312345678, y: 50.4587654321);
+
+    assert_eq!(point!(x: -67.031235, y: 50.458765), ruler.round_to(&p, 6));
+    assert_eq!(point!(x: -67.0, y: 50.0), ruler.round_to(&p, 0));
+
+    let line = line_string![
+        (x: -67.0312345678, y: 50.4587654321),
+        (x: -66.9291111111, y: 50.5349999999),
+    ];
+    assert_eq!(
+        line_string![(x: -67.031, y: 50.459), (x: -66.929, y: 50.535)],
+        ruler.round_line_to(&line, 3)
+    );
+}
+
+#[test]
+fn test_line_distance_empty() {
+    let ruler = fixtures::ruler_km();
+
+    let empty_line = line_string![];
+    let expected = 0.0;
+    let actual = ruler.line_distance(&empty_line);
+
+    assert_eq_err!(expected, actual, 0.0);
+}
+
+#[test]
+fn test_line_distance() {
+    let ruler = fixtures::ruler_km();
+
+    for i in 0..LINES.len() {
+        let expected = turf::LINEDISTANCE[i];
+        let actual = ruler.line_distance(&LINES[i]);
+
+        assert_eq_err!(expected, actual, 0.003);
+    }
+}
+
+#[test]
+fn test_distances() {
+    let ruler = fixtures::ruler_km();
+
+    let actual = ruler.distances(POINTS.iter().cloned()).collect::<Vec<f64>>();
+
+    assert_eq!(POINTS.len() - 1, actual.len());
+    for i in 0..POINTS.len() - 1 {
+        assert_eq!(ruler.distance(&POINTS[i], &POINTS[i + 1]), actual[i]);
+    }
+
+    assert_eq!(0, ruler.distances(POINTS.iter().cloned().take(1)).count());
+    assert_eq!(0, ruler.distances(vec![]).count());
+}
+
+#[test]
+fn test_accumulate_distance() {
+    let ruler = fixtures::ruler_km();
+
+    for i in 0..LINES.len() {
+        let line = &LINES[i];
+        let totals = ruler.accumulate_distance(line).collect::<Vec<f64>>();
+
+        assert_eq!(line.num_coords(), totals.len());
+        assert_eq!(0.0, totals[0]);
+        for j in 1..totals.len() {
+            let d = ruler.distance(&line[j - 1].into(), &line[j].into());
+            assert_eq_err!(totals[j - 1] + d, totals[j], 1e-12);
+        }
+        let last = totals[totals.len() - 1];
+        assert_eq_err!(ruler.line_distance(line), last, 1e-12);
+    }
+
+    let single = line_string![(x: -77.0, y: 38.0)];
+    let totals = ruler.accumulate_distance(&single).collect::<Vec<f64>>();
+    assert_eq!(vec![0.0], totals);
+    assert_eq!(0, ruler.accumulate_distance(&line_string![]).count());
+}
+
+#[test]
+fn test_iter_segments_with_distance() {
+    let ruler = fixtures::ruler_km();
+
+    for i in 0..LINES.len() {
+        let segments = ruler
+            .iter_segments_with_distance(&LINES[i])
+            .collect::<Vec<_>>();
+
+        assert_eq!(LINES[i].num_coords() - 1, segments.len());
+        for (j, (a, b, d)) in segments.iter().enumerate() {
+            assert_eq!(point!(x: LINES[i][j].x, y: LINES[i][j].y), *a);
+            assert_eq!(point!(x: LINES[i][j + 1].x, y: LINES[i][j + 1].y), *b);
+            assert_eq!(ruler.distance(a, b), *d);
+        }
+
+        let total: f64 = segments.iter().map(|(_, _, d)| d).sum();
+        assert_eq_err!(ruler.line_distance(&LINES[i]), total, 1e-12);
+    }
+
+    let single = line_string![(x: -96.920341, y: 32.838261)];
+    assert_eq!(0, ruler.iter_segments_with_distance(&single).count());
+    assert_eq!(0, ruler.iter_segments_with_distance(&line_string![]).count());
+}
+
+#[test]
+fn test_lines_approx_equal() {
+    let ruler = fixtures::ruler_km();
+    let line = &LINES[0];
+    let shifted: LineString<f64> = line
+        .0
+        .iter()
+        .map(|&c| Coordinate {
+            x: c.x + 1e-7,
+            y: c.y - 1e-7,
+        })
+        .collect::<Vec<_>>()
+        .into();
+
+    assert!(ruler.lines_approx_equal(line, line, 0.0));
+    assert!(ruler.lines_approx_equal(line, &shifted, 0.001));
+    assert!(!ruler.lines_approx_equal(line, &shifted, 1e-6));
+
+    let mut shorter = line.clone();
+    shorter.0.pop();
+    assert!(!ruler.lines_approx_equal(line, &shorter, f64::INFINITY));
+}
+
+#[test]
+fn test_clean_line() {
+    let ruler = fixtures::ruler_km();
+    let line = line_string![
+        (x: -67.031, y: 50.458),
+        (x: -67.031, y: 50.458),
+        (x: -67.031, y: 50.534),
+        (x: -67.03100001, y: 50.534),
+        (x: -66.929, y: 50.534),
+        (x: -66.929, y: 50.53400001),
+    ];
+
+    let cleaned = ruler.clean_line(&line, 1e-3);
+    assert_eq!(
+        line_string![
+            (x: -67.031, y: 50.458),
+            (x: -67.031, y: 50.534),
+            (x: -66.929, y: 50.53400001),
+        ],
+        cleaned
+    );
+    assert_eq_err!(
+        ruler.line_distance(&line),
+        ruler.line_distance(&cleaned),
+        1e-6
+    );
+
+    let dot = line_string![(x: -67.031, y: 50.458), (x: -67.031, y: 50.458)];
+    assert_eq!(dot, ruler.clean_line(&dot, 1e-3));
+    assert_eq!(line, ruler.clean_line(&line, 0.0));
+    assert_eq!(line_string![], ruler.clean_line(&line_string![], 1e-3));
+}
+
+#[test]
+fn test_segment_bearings() {
+    let ruler = fixtures::ruler_km();
+    let line = line_string![
+        (x: -96.9, y: 32.83),
+        (x: -96.9, y: 32.84),
+        (x: -96.9, y: 32.84),
+        (x: -96.89, y: 32.84),
+    ];
+
+    let bearings = ruler.segment_bearings(&line);
+    assert_eq!(3, bearings.len());
+    assert_eq_err!(0.0, bearings[0], 1e-12);
+    assert!(bearings[1].is_nan());
+    assert_eq_err!(90.0, bearings[2], 1e-3);
+    assert_eq!(
+        ruler.bearing(&line[2].into(), &line[3].into()),
+        bearings[2]
+    );
+
+    let single = line_string![(x: -96.9, y: 32.83)];
+    assert!(ruler.segment_bearings(&single).is_empty());
+    assert!(ruler.segment_bearings(&line_string![]).is_empty());
+}
+
+#[test]
+fn test_flag_heading_outliers() {
+    let ruler = fixtures::ruler_km();
+    let trace = line_string![
+        (x: -96.9, y: 32.83),
+        (x: -96.9, y: 32.831),
+        (x: -96.8999, y: 32.832),
+        (x: -96.9005, y: 32.8315),
+        (x: -96.8998, y: 32.833),
+        (x: -96.8998, y: 32.834),
+    ];
+
+    assert_eq!(
+        vec![false, false, true, true, false, false],
+        ruler.flag_heading_outliers(&trace, 45.0)
+    );
+    assert_eq!(
+        vec![false; 6],
+        ruler.flag_heading_outliers(&trace, 180.0)
+    );
+    let straight = line_string![(x: -96.9, y: 32.83), (x: -96.9, y: 32.84)];
+    let flags = ruler.flag_heading_outliers(&straight, 0.0);
+    assert_eq!(vec![false, false], flags);
+    assert!(ruler.flag_heading_outliers(&line_string![], 0.0).is_empty());
+}
+
+#[test]
+fn test_fold_segments() {
+    let ruler = fixtures::ruler_km();
+
+    for i in 0..LINES.len() {
+        let line = &LINES[i];
+        let length = ruler.fold_segments(line, 0.0, |sum, _, d| sum + d);
+        assert_eq!(ruler.line_distance(line), length);
+
+        let segments = ruler.fold_segments(line, vec![], |mut acc, j, d| {
+            acc.push((j, d));
+            acc
+        });
+        assert_eq!(line.num_coords() - 1, segments.len());
+        for (j, &(index, d)) in segments.iter().enumerate() {
+            assert_eq!(j, index);
+            assert_eq!(ruler.distance(&line[j].into(), &line[j + 1].into()), d);
+        }
+    }
+
+    let count = ruler.fold_segments(&line_string![], 0, |n, _, _| n + 1);
+    assert_eq!(0, count);
+}
+
+#[test]
+fn test_simplify_keeping() {
+    let ruler = fixtures::ruler_km();
+    let line = line_string![
+        (x: -96.9, y: 32.83),
+        (x: -96.899, y: 32.83001),
+        (x: -96.898, y: 32.83),
+        (x: -96.897, y: 32.83),
+        (x: -96.896, y: 32.84),
+        (x: -96.895, y: 32.84001),
+        (x: -96.894, y: 32.84),
+    ];
+
+    assert_eq!(
+        line_string![
+            (x: -96.9, y: 32.83),
+            (x: -96.897, y: 32.83),
+            (x: -96.896, y: 32.84),
+            (x: -96.894, y: 32.84),
+        ],
+        ruler.simplify_keeping(&line, 0.01, &[])
+    );
+    assert_eq!(
+        line_string![
+            (x: -96.9, y: 32.83),
+            (x: -96.899, y: 32.83001),
+            (x: -96.897, y: 32.83),
+            (x: -96.896, y: 32.84),
+            (x: -96.894, y: 32.84),
+        ],
+        ruler.simplify_keeping(&line, 0.01, &[1, 100])
+    );
+    assert_eq!(line, ruler.simplify_keeping(&line, 0.0, &[]));
+
+    let short = line_string![(x: -96.9, y: 32.83), (x: -96.8, y: 32.83)];
+    assert_eq!(short, ruler.simplify_keeping(&short, 1.0, &[]));
+}
+
+#[test]
+fn test_checked_line_distance() {
+    let ruler = fixtures::ruler_km();
+
+    for line in LINES.iter() {
+        assert_eq!(
+            Ok(ruler.line_distance(line)),
+            ruler.checked_line_distance(line)
+        );
+    }
+    assert_eq!(Ok(0.0), ruler.checked_line_distance(&line_string![]));
+
+    let mut invalid = LINES[0].clone();
+    invalid.0[1].y = f64::NAN;
+    invalid.0[2].x = f64::INFINITY;
+    assert_eq!(Err(1), ruler.checked_line_distance(&invalid));
+}
+
+#[test]
+fn test_line_distance_3d() {
+    let ruler = fixtures::ruler_km();
+    let line = &LINES[0];
+    let n = line.num_coords();
+
+    let flat = vec![0.5; n];
+    assert_eq_err!(
+        ruler.line_distance(line),
+        ruler.line_distance_3d(line, &flat),
+        1e-12
+    );
+
+    let climbing = (0..n).map(|i| i as f64 * 0.1).collect::<Vec<f64>>();
+    let expected = (0..n - 1)
+        .map(|i| ruler.distance(&line[i].into(), &line[i + 1].into()))
+        .map(|d| (d * d + 0.01).sqrt())
+        .sum::<f64>();
+    let actual = ruler.line_distance_3d(line, &climbing);
+    assert_eq_err!(expected, actual, 1e-12);
+    assert!(actual > ruler.line_distance(line));
+}
+
+#[test]
+#[should_panic(expected = "One elevation per vertex expected")]
+fn test_line_distance_3d_missing_elevations() {
+    let ruler = fixtures::ruler_km();
+    ruler.line_distance_3d(&LINES[0], &[0.0]);
+}
+
+#[test]
+fn test_stitch() {
+    let ruler = fixtures::ruler_km();
+
+    let lines = MultiLineString(vec![
+        line_string![(x: 30.2, y: 32.8), (x: 30.3, y: 32.8)],
+        line_string![(x: 30.1, y: 32.8), (x: 30.0, y: 32.8)],
+        line_string![(x: 50.0, y: 10.0), (x: 50.1, y: 10.0)],
+        line_string![(x: 30.4, y: 32.8), (x: 30.3000001, y: 32.8)],
+        line_string![(x: 30.1, y: 32.8), (x: 30.2, y: 32.8)],
+    ]);
+    let stitched = ruler.stitch(&lines, 0.001);
+
+    assert_eq!(
+        vec![
+            line_string![
+                (x: 30.0, y: 32.8),
+                (x: 30.1, y: 32.8),
+                (x: 30.2, y: 32.8),
+                (x: 30.3, y: 32.8),
+                (x: 30.3000001, y: 32.8),
+                (x: 30.4, y: 32.8),
+            ],
+            line_string![(x: 50.0, y: 10.0), (x: 50.1, y: 10.0)],
+        ],
+        stitched
+    );
+
+    assert!(ruler.stitch(&MultiLineString(vec![]), 0.001).is_empty());
+}
+
+#[test]
+fn test_overlap_length() {
+    let ruler = fixtures::ruler_km();
+
+    let a = line_string![
+        (x: 30.50, y: 32.8351),
+        (x: 30.51, y: 32.8351),
+        (x: 30.52, y: 32.8351),
+        (x: 30.53, y: 32.8451),
+    ];
+    let b = line_string![
+        (x: 30.49, y: 32.83511),
+        (x: 30.525, y: 32.83511),
+    ];
+
+    let expected = ruler.distance(&a[0].into(), &a[2].into());
+    assert_eq_err!(expected, ruler.overlap_length(&a, &b, 0.01), 1e-12);
+    assert_eq!(0.0, ruler.overlap_length(&a, &b, 0.0001));
+    assert_eq!(0.0, ruler.overlap_length(&a, &line_string![], 1.0));
+    assert_eq_err!(
+        ruler.line_distance(&a),
+        ruler.overlap_length(&a, &a, 0.0),
+        1e-12
+    );
+}
+
+#[test]
+fn test_length_accumulator() {
+    let ruler = fixtures::ruler_km();
+
+    for i in 0..LINES.len() {
+        let mut acc = LengthAccumulator::new(ruler.clone());
+        for p in LINES[i].points_iter() {
+            acc.push(p);
+        }
+
+        assert_eq_err!(ruler.line_distance(&LINES[i]), acc.total(), 1e-12);
+    }
+
+    let empty = LengthAccumulator::new(ruler);
+    assert_eq!(0.0, empty.total());
+}
+
+#[test]
+fn test_total_length() {
+    let ruler = fixtures::ruler_km();
+
+    let expected: f64 = LINES.iter().map(|l| ruler.line_distance(l)).sum();
+    let actual = ruler.total_length(LINES.iter());
+
+    assert_eq_err!(expected, actual, 1e-12);
+    assert_eq!(0.0, ruler.total_length(LINES.iter().take(0)));
+    assert_eq!(0.0, ruler.total_length(&Vec::<LineString<f64>>::new()));
+}
+
+#[test]
+fn test_line_distance_at_most() {
+    let ruler = fixtures::ruler_km();
+
+    for i in 0..LINES.len() {
+        let dist = ruler.line_distance(&LINES[i]);
+
+        let actual = ruler
+            .line_distance_at_most(&LINES[i], dist * 1.01)
+            .expect("Line shorter than max");
+        assert_eq_err!(dist, actual, 1e-12);
+
+        if dist > 0.0 {
+            assert!(ruler
+                .line_distance_at_most(&LINES[i], dist * 0.99)
+                .is_none());
+        }
+    }
+
+    assert_eq!(
+        Some(0.0),
+        ruler.line_distance_at_most(&line_string![], 0.0)
+    );
+}
+
+#[test]
+fn test_area() {
+    let ruler = fixtures::ruler_km();
+
+    let mut j = 0;
+    for i in 0..LINES.len() {
+        if LINES[i].points_iter().count() < 3 {
+            continue;
+        }
+
+        let mut ring = LINES[i].to_owned().into_points();
+        ring.push(point!(x: LINES[i][0].x, y: LINES[i][0].y));
+
+        let polygon = Polygon::new(LineString::from(ring), vec![]);
+        let expected = turf::AREA[j];
+        let actual = ruler.area(&polygon);
+
+        assert_eq_err!(expected, actual, 0.003);
+
+        j += 1;
+    }
+}
+
+#[test]
+fn test_total_area() {
+    let ruler = fixtures::ruler_km();
+    let polygons = LINES
+        .iter()
+        .filter(|line| line.0.len() >= 3)
+        .map(|line| Polygon::new(line.clone(), vec![]))
+        .collect::<Vec<Polygon<f64>>>();
+
+    let expected: f64 = polygons.iter().map(|p| ruler.area(p)).sum();
+    assert_eq_err!(expected, ruler.total_area(polygons.iter()), 1e-12);
+    assert_eq_err!(
+        ruler.area(&polygons[0]),
+        ruler.total_area(polygons.iter().take(1)),
+        1e-12
+    );
+    assert_eq!(0.0, ruler.total_area(polygons.iter().take(0)));
+    assert_eq!(0.0, ruler.total_area(&Vec::<Polygon<f64>>::new()));
+}
+
+#[test]
+fn test_area_with_orientation() {
+    let ruler = fixtures::ruler_km();
+
+    let ccw = Polygon::new(
+        line_string![
+            (x: -67.031, y: 50.458),
+            (x: -66.929, y: 50.458),
+            (x: -66.929, y: 50.534),
+            (x: -67.031, y: 50.534),
+            (x: -67.031, y: 50.458),
+        ],
+        vec![],
+    );
+    let mut cw_ring = ccw.exterior().clone().into_points();
+    cw_ring.reverse();
+    let cw = Polygon::new(LineString::from(cw_ring), vec![]);
+
+    let (area, orientation) = ruler.area_with_orientation(&ccw);
+    assert_eq!(ruler.area(&ccw), area);
+    assert_eq!(Orientation::CounterClockwise, orientation);
+
+    let (area, orientation) = ruler.area_with_orientation(&cw);
+    assert_eq!(ruler.area(&cw), area);
+    assert_eq!(Orientation::Clockwise, orientation);
+}
+
+#[test]
+fn test_area_with_holes() {
+    let ruler = fixtures::ruler_km();
+    let origin = point!(x: -96.9, y: 32.8351);
+    let corner = |dx: f64, dy: f64| ruler.offset(&origin, dx, dy);
+    let square = |x: f64, y: f64, size: f64| {
+        LineString::from(vec![
+            corner(x, y),
+            corner(x + size, y),
+            corner(x + size, y + size),
+            corner(x, y + size),
+            corner(x, y),
+        ])
+    };
+    let reversed = |ring: &LineString<f64>| {
+        let mut points = ring.clone().into_points();
+        points.reverse();
+        LineString::from(points)
+    };
+    let exterior = square(0.0, 0.0, 4.0);
+    let hole = square(1.0, 1.0, 1.0);
+    let other_hole = square(2.5, 2.5, 1.0);
+
+    // holes wound opposite to the exterior, and the same way
+    let opposite = Polygon::new(exterior.clone(), vec![reversed(&hole)]);
+    let same = Polygon::new(exterior.clone(), vec![hole.clone()]);
+    let mixed = Polygon::new(
+        reversed(&exterior),
+        vec![hole.clone(), reversed(&other_hole)],
+    );
+
+    assert_eq_err!(15.0, ruler.area(&opposite), 1e-9);
+    assert_eq_err!(15.0, ruler.area(&same), 1e-9);
+    assert_eq_err!(14.0, ruler.area(&mixed), 1e-9);
+    assert_eq!(
+        Orientation::CounterClockwise,
+        ruler.area_with_orientation(&same).1
+    );
+    assert_eq!(Orientation::Clockwise, ruler.area_with_orientation(&mixed).1);
+}
+
+#[test]
+fn test_normalize_winding() {
+    let ruler = fixtures::ruler_km();
+
+    let exterior = line_string![
+        (x: -67.031, y: 50.458),
+        (x: -66.929, y: 50.458),
+        (x: -66.929, y: 50.534),
+        (x: -67.031, y: 50.534),
+        (x: -67.031, y: 50.458),
+    ];
+    let interior = line_string![
+        (x: -67.0, y: 50.48),
+        (x: -67.0, y: 50.5),
+        (x: -66.95, y: 50.5),
+        (x: -66.95, y: 50.48),
+        (x: -67.0, y: 50.48),
+    ];
+    let reversed = |ring: &LineString<f64>| {
+        let mut points = ring.clone().into_points();
+        points.reverse();
+        LineString::from(points)
+    };
+
+    let normalized = Polygon::new(exterior.clone(), vec![interior.clone()]);
+    assert!(ruler.is_exterior_ccw(&normalized));
+    assert_eq!(normalized, ruler.normalize_winding(&normalized));
+
+    let inverted =
+        Polygon::new(reversed(&exterior), vec![reversed(&interior)]);
+    assert!(!ruler.is_exterior_ccw(&inverted));
+    let fixed = ruler.normalize_winding(&inverted);
+    assert!(ruler.is_exterior_ccw(&fixed));
+    assert_eq!(ruler.area(&inverted), ruler.area(&fixed));
+    assert_eq!(
+        Orientation::Clockwise,
+        ruler
+            .area_with_orientation(&Polygon::new(
+                fixed.interiors()[0].clone(),
+                vec![]
+            ))
+            .1
+    );
+
+    // normalizing doesn't change the area, even for holes wound the same way
+    // as the exterior
+    let same_winding = Polygon::new(exterior, vec![reversed(&interior)]);
+    assert_eq!(ruler.area(&normalized), ruler.area(&same_winding));
+    assert_eq!(
+        ruler.area(&normalized),
+        ruler.area(&ruler.normalize_winding(&normalized))
+    );
+    assert_eq!(
+        ruler.area(&same_winding),
+        ruler.area(&ruler.normalize_winding(&same_winding))
+    );
+}
+
+#[test]
+fn test_perimeter() {
+    let ruler = fixtures::ruler_km();
+    let side = 2.0;
+    let origin = point!(x: -96.9, y: 32.8351);
+    let corner = |dx: f64, dy: f64| ruler.offset(&origin, dx, dy);
+    let square = |size: f64| {
+        LineString::from(vec![
+            corner(0.0, 0.0),
+            corner(size, 0.0),
+            corner(size, size),
+            corner(0.0, size),
+        ])
+    };
+
+    let polygon = Polygon::new(square(side), vec![]);
+    assert_eq_err!(4.0 * side, ruler.perimeter(&polygon), 1e-12);
+
+    let with_hole = Polygon::new(square(side), vec![square(1.0)]);
+    assert_eq_err!(4.0 * side + 4.0, ruler.perimeter(&with_hole), 1e-12);
+}
+
+#[test]
+fn test_point_on_perimeter() {
+    let ruler = fixtures::ruler_km();
+    let origin = point!(x: -96.9, y: 32.8351);
+    let corner = |dx: f64, dy: f64| ruler.offset(&origin, dx, dy);
+    let polygon = Polygon::new(
+        LineString::from(vec![
+            corner(0.0, 0.0),
+            corner(2.0, 0.0),
+            corner(2.0, 2.0),
+            corner(0.0, 2.0),
+        ]),
+        vec![],
+    );
+    let assert_point = |expected: Point<f64>, dist: f64| {
+        let actual = ruler.point_on_perimeter(&polygon, dist).unwrap();
+        assert_eq_err!(expected.lng(), actual.lng(), 1e-12);
+        assert_eq_err!(expected.lat(), actual.lat(), 1e-12);
+    };
+
+    assert_point(corner(0.0, 0.0), 0.0);
+    assert_point(corner(2.0, 1.0), 3.0);
+    // the closing segment is walked too
+    assert_point(corner(0.0, 1.0), 7.0);
+    assert_point(corner(0.0, 0.0), 8.0);
+    assert_point(corner(2.0, 1.0), 11.0);
+    assert_point(corner(0.0, 1.0), -1.0);
+
+    let empty = Polygon::new(line_string![], vec![]);
+    assert_eq!(None, ruler.point_on_perimeter(&empty, 1.0));
+}
+
+#[test]
+fn test_overlap_ratio() {
+    let ruler = fixtures::ruler_km();
+    let origin = point!(x: -96.9, y: 32.8351);
+    let square = |x: f64, y: f64, size: f64| {
+        let corner = |dx: f64, dy: f64| ruler.offset(&origin, x + dx, y + dy);
+        Polygon::new(
+            LineString::from(vec![
+                corner(0.0, 0.0),
+                corner(size, 0.0),
+                corner(size, size),
+                corner(0.0, size),
+            ]),
+            vec![],
+        )
+    };
+
+    let a = square(0.0, 0.0, 2.0);
+    assert_eq_err!(1.0, ruler.overlap_ratio(&a, &a), 1e-9);
+    let half = square(1.0, 0.0, 2.0);
+    assert_eq_err!(0.5, ruler.overlap_ratio(&a, &half), 1e-9);
+    let quarter = square(1.0, 1.0, 2.0);
+    assert_eq_err!(0.25, ruler.overlap_ratio(&a, &quarter), 1e-9);
+    // relative to the smaller polygon, whatever the order or winding
+    let inner = square(0.5, 0.5, 1.0);
+    assert_eq_err!(1.0, ruler.overlap_ratio(&a, &inner), 1e-9);
+    let mut cw = inner.exterior().clone().into_points();
+    cw.reverse();
+    let inner_cw = Polygon::new(LineString::from(cw), vec![]);
+    assert_eq_err!(1.0, ruler.overlap_ratio(&inner_cw, &a), 1e-9);
+
+    assert_eq!(0.0, ruler.overlap_ratio(&a, &square(3.0, 0.0, 2.0)));
+    let empty = Polygon::new(line_string![], vec![]);
+    assert_eq!(0.0, ruler.overlap_ratio(&a, &empty));
+    assert_eq!(0.0, ruler.overlap_ratio(&empty, &a));
+}
+
+#[test]
+fn test_spherical_area() {
+    let ruler = CheapRuler::new(50.5, DistanceUnit::Kilometers);
+    let ruler_m = CheapRuler::new(50.5, DistanceUnit::Meters);
+
+    let polygon = Polygon::new(
+        line_string![
+            (x: 30.5, y: 50.45),
+            (x: 30.6, y: 50.45),
+            (x: 30.6, y: 50.55),
+            (x: 30.5, y: 50.55),
+            (x: 30.5, y: 50.45),
+        ],
+        vec![line_string![
+            (x: 30.52, y: 50.47),
+            (x: 30.52, y: 50.49),
+            (x: 30.54, y: 50.49),
+            (x: 30.54, y: 50.47),
+            (x: 30.52, y: 50.47),
+        ]],
+    );
+
+    let ring_area = |ring: &LineString<f64>| {
+        ruler.area(&Polygon::new(ring.clone(), vec![]))
+    };
+    let hole = &polygon.interiors()[0];
+    let planar = ring_area(polygon.exterior()) - ring_area(hole);
+    let spherical = ruler.spherical_area(&polygon);
+
+    assert_eq_err!(planar, spherical, 0.01);
+    assert_eq_err!(spherical * 1e6, ruler_m.spherical_area(&polygon), 1e-12);
+
+    // the hole is subtracted whatever its winding
+    let mut reversed = hole.clone().into_points();
+    reversed.reverse();
+    let same_winding = Polygon::new(
+        polygon.exterior().clone(),
+        vec![LineString::from(reversed)],
+    );
+    assert_eq_err!(spherical, ruler.spherical_area(&same_winding), 1e-12);
+}
+
+#[test]
+fn test_measure() {
+    let ruler = fixtures::ruler_km();
+
+    let line = LINES[0].clone();
+    let length = ruler.line_distance(&line);
+    let polygon = Polygon::new(
+        line_string![
+            (x: -67.031, y: 50.458),
+            (x: -67.031, y: 50.534),
+            (x: -66.929, y: 50.534),
+            (x: -66.929, y: 50.458),
+            (x: -67.031, y: 50.458),
+        ],
+        vec![],
+    );
+    let area = ruler.area(&polygon);
+
+    assert_eq!(
+        Measurement::Zero,
+        ruler.measure(&Geometry::Point(POINTS[0]))
+    );
+    assert_eq!(
+        Measurement::Zero,
+        ruler.measure(&Geometry::MultiPoint(MultiPoint(vec![POINTS[0]])))
+    );
+    assert_eq!(
+        Measurement::Length(length),
+        ruler.measure(&Geometry::LineString(line.clone()))
+    );
+    assert_eq!(
+        Measurement::Length(length * 2.0),
+        ruler.measure(&Geometry::MultiLineString(MultiLineString(vec![
+            line.clone(),
+            line.clone(),
+        ])))
+    );
+    assert_eq!(
+        Measurement::Area(area),
+        ruler.measure(&Geometry::Polygon(polygon.clone()))
+    );
+
+    // collections are summed recursively
+    let collection = GeometryCollection(vec![
+        Geometry::Point(POINTS[0]),
+        Geometry::LineString(line.clone()),
+        Geometry::GeometryCollection(GeometryCollection(vec![
+            Geometry::LineString(line),
+            Geometry::Polygon(polygon),
+        ])),
+    ]);
+    match ruler.measure(&Geometry::GeometryCollection(collection)) {
+        Measurement::Mixed {
+            length: actual_length,
+            area: actual_area,
+        } => {
+            assert_eq_err!(length * 2.0, actual_length, 1e-12);
+            assert_eq_err!(area, actual_area, 1e-12);
+        }
+        other => panic!("Unexpected measurement {:?}", other),
+    }
+}
+
+#[test]
+fn test_along() {
+    let ruler = fixtures::ruler_km();
+
+    for i in 0..LINES.len() {
+        let expected = turf::ALONG[i];
+        let actual = ruler
+            .along(&LINES[i], turf::ALONG_DIST[i])
+            .expect("Non-empty line string given");
+
+        assert_eq_err!(expected.lng(), actual.lng(), 1e-6); // along longitude
+        assert_eq_err!(expected.lat(), actual.lat(), 1e-6); // along latitude
+    }
+}
+
+#[test]
+fn test_along_empty() {
+    let ruler = fixtures::ruler_km();
+
+    let empty_line = line_string![];
+    let actual = ruler.along(&empty_line, 0.0);
+
+    assert!(actual.is_none());
+}
+
+#[test]
+fn test_along_with_dist_lt_0() {
+    let ruler = fixtures::ruler_km();
+
+    let coord = LINES[0][0];
+    let actual = ruler
+        .along(&LINES[0], -5.0)
+        .expect("Non-empty line string given");
+
+    assert_eq!(point!(x: coord.x, y: coord.y), actual);
+}
+
+#[test]
+fn test_along_with_dist_greater_than_length() {
+    let ruler = fixtures::ruler_km();
+
+    let coord = LINES[0].points_iter().next_back().expect("Last element");
+    let actual = ruler
+        .along(&LINES[0], 1000.0)
+        .expect("Non-empty line string given");
+
+    assert_eq!(point!(x: coord.lng(), y: coord.lat()), actual);
+}
+
+#[test]
+fn test_multiline_along() {
+    let ruler = fixtures::ruler_km();
+    let first = line_string![
+        (x: -67.031, y: 50.458),
+        (x: -67.031, y: 50.534),
+    ];
+    let second = line_string![
+        (x: -66.929, y: 50.534),
+        (x: -66.929, y: 50.458),
+    ];
+    let first_len = ruler.line_distance(&first);
+    let lines = MultiLineString(vec![
+        line_string![],
+        first.clone(),
+        second.clone(),
+    ]);
+
+    assert_eq!(
+        ruler.along(&first, -1.0),
+        ruler.multiline_along(&lines, -1.0)
+    );
+    assert_eq!(
+        ruler.along(&first, first_len / 2.0),
+        ruler.multiline_along(&lines, first_len / 2.0)
+    );
+    assert_eq!(
+        ruler.along(&second, 2.0),
+        ruler.multiline_along(&lines, first_len + 2.0)
+    );
+    assert_eq!(
+        second.points_iter().next_back(),
+        ruler.multiline_along(&lines, 1000.0)
+    );
+    assert_eq!(
+        None,
+        ruler.multiline_along(&MultiLineString(vec![line_string![]]), 1.0)
+    );
+}
+
+#[test]
+fn test_along_many() {
+    let ruler = fixtures::ruler_km();
+
+    for i in 0..LINES.len() {
+        let dist = ruler.line_distance(&LINES[i]);
+        let dists = [
+            dist * 0.7,
+            -1.0,
+            turf::ALONG_DIST[i],
+            dist * 0.2,
+            dist * 2.0,
+            dist * 0.7,
+        ];
+
+        let actual = ruler.along_many(&LINES[i], &dists);
+
+        assert_eq!(dists.len(), actual.len());
+        for (d, p) in dists.iter().zip(actual) {
+            assert_eq!(ruler.along(&LINES[i], *d), p);
+        }
+    }
+
+    assert_eq!(
+        vec![None, None],
+        ruler.along_many(&line_string![], &[0.0, 1.0])
+    );
+
+    // NaN distances don't affect the other results
+    let origin = point!(x: 30.5, y: 32.8351);
+    let line = LineString::from(vec![
+        origin,
+        ruler.offset(&origin, 4.0, 0.0),
+        ruler.offset(&origin, 4.0, 6.0),
+    ]);
+    let dists = [1.0, f64::NAN, 5.0, 2.0, f64::NAN];
+    let actual = ruler.along_many(&line, &dists);
+    for (d, p) in dists.iter().zip(actual) {
+        assert_eq!(ruler.along(&line, *d), p);
+    }
+}
+
+#[test]
+fn test_resample_with_distances() {
+    let ruler = fixtures::ruler_km();
+
+    for line in LINES.iter() {
+        let length = ruler.line_distance(line);
+        let (resampled, dists) = ruler.resample_with_distances(line, 10);
+
+        assert_eq!(10, resampled.0.len());
+        assert_eq!(10, dists.len());
+        assert_eq!(0.0, dists[0]);
+        assert_eq_err!(length, dists[9], 1e-12);
+        assert_eq!(line.0[0], resampled.0[0]);
+        assert_eq_err!(line.0[line.0.len() - 1].x, resampled.0[9].x, 1e-9);
+        assert_eq_err!(line.0[line.0.len() - 1].y, resampled.0[9].y, 1e-9);
+        for (p, &d) in resampled.points_iter().zip(dists.iter()) {
+            assert_eq!(ruler.along(line, d), Some(p));
+        }
+        for d in dists.windows(2) {
+            assert_eq_err!(length / 9.0, d[1] - d[0], 1e-9);
+        }
+    }
+
+    let (resampled, dists) = ruler.resample_with_distances(&line_string![], 3);
+    assert!(resampled.0.is_empty());
+    assert!(dists.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "At least 2 points expected")]
+fn test_resample_with_distances_single_point() {
+    let ruler = fixtures::ruler_km();
+
+    ruler.resample_with_distances(&LINES[0], 1);
+}
+
+#[test]
+fn test_bearing_at() {
+    let ruler = fixtures::ruler_km();
+    let line = line_string![
+        (x: -96.9, y: 32.83),
+        (x: -96.9, y: 32.84),
+        (x: -96.89, y: 32.84),
+    ];
+    let first = ruler.bearing(&line[0].into(), &line[1].into());
+    let second = ruler.bearing(&line[1].into(), &line[2].into());
+    let first_len = ruler.distance(&line[0].into(), &line[1].into());
+
+    assert_eq!(Some(first), ruler.bearing_at(&line, first_len / 2.0));
+    assert_eq!(Some(second), ruler.bearing_at(&line, first_len + 0.1));
+
+    // clamped at the ends
+    assert_eq!(Some(first), ruler.bearing_at(&line, -1.0));
+    assert_eq!(Some(second), ruler.bearing_at(&line, 1000.0));
+
+    let single = line_string![(x: -96.9, y: 32.83)];
+    assert_eq!(None, ruler.bearing_at(&single, 0.0));
+    assert_eq!(None, ruler.bearing_at(&line_string![], 0.0));
+}
+
+#[test]
+fn test_cross_sections() {
+    let ruler = fixtures::ruler_km();
+    let origin = point!(x: -96.9, y: 32.8351);
+    let line = LineString::from(vec![
+        origin,
+        ruler.offset(&origin, 2.0, 0.0),
+        ruler.offset(&origin, 2.0, 2.0),
+    ]);
+    let assert_coord = |expected: Point<f64>, actual: Coordinate<f64>| {
+        assert_eq_err!(expected.lng(), actual.x, 1e-9);
+        assert_eq_err!(expected.lat(), actual.y, 1e-9);
+    };
+
+    let sections = ruler.cross_sections(&line, 1.0, 0.5);
+    assert_eq!(5, sections.len());
+
+    // heading east, from the north side to the south side
+    assert_coord(ruler.offset(&origin, 0.0, 0.5), sections[0].start);
+    assert_coord(ruler.offset(&origin, 0.0, -0.5), sections[0].end);
+    assert_coord(ruler.offset(&origin, 1.0, 0.5), sections[1].start);
+    assert_coord(ruler.offset(&origin, 1.0, -0.5), sections[1].end);
+    // heading north, from the west side to the east side
+    assert_coord(ruler.offset(&origin, 1.5, 1.0), sections[3].start);
+    assert_coord(ruler.offset(&origin, 2.5, 1.0), sections[3].end);
+    assert_coord(ruler.offset(&origin, 1.5, 2.0), sections[4].start);
+    assert_coord(ruler.offset(&origin, 2.5, 2.0), sections[4].end);
+
+    for section in sections.iter() {
+        assert_eq_err!(1.0, ruler.line_length(section), 1e-9);
+    }
+
+    assert_eq!(3, ruler.cross_sections(&line, 1.5, 0.5).len());
+    let single = line_string![(x: -96.9, y: 32.8351)];
+    assert!(ruler.cross_sections(&single, 1.0, 0.5).is_empty());
+}
+
+#[test]
+#[should_panic(expected = "Interval must be positive")]
+fn test_cross_sections_zero_interval() {
+    let ruler = fixtures::ruler_km();
+
+    ruler.cross_sections(&LINES[0], 0.0, 0.5);
+}
+
+#[test]
+fn test_along_with_index() {
+    let ruler = fixtures::ruler_km();
+
+    for i in 0..LINES.len() {
+        let (point, index) = ruler
+            .along_with_index(&LINES[i], turf::ALONG_DIST[i])
+            .expect("Non-empty line string given");
+        let expected = ruler
+            .along(&LINES[i], turf::ALONG_DIST[i])
+            .expect("Non-empty line string given");
+
+        assert_eq!(expected, point);
+        assert!(index < LINES[i].num_coords() - 1);
+        assert!(
+            ruler.point_to_segment_distance(
+                &point,
+                &LINES[i][index].into(),
+                &LINES[i][index + 1].into()
+            ) < 1e-9
+        );
+    }
+
+    let line = &LINES[0];
+    let (_, first) = ruler
+        .along_with_index(line, -5.0)
+        .expect("Non-empty line string given");
+    let (_, last) = ruler
+        .along_with_index(line, 1000.0)
+        .expect("Non-empty line string given");
+
+    assert_eq!(0, first);
+    assert_eq!(line.num_coords() - 2, last);
+    assert!(ruler.along_with_index(&line_string![], 0.0).is_none());
+}
+
+#[test]
+fn test_coords_variants() {
+    let ruler = fixtures::ruler_km();
+
+    for i in 0..LINES.len() {
+        let line = &LINES[i];
+        let coords: &[Coordinate<f64>] = &line.0;
+        let dist = ruler.line_distance(line);
+        let start = ruler
+            .along(line, dist * 0.3)
+            .expect("Non-empty line string given");
+        let stop = ruler
+            .along(line, dist * 0.7)
+            .expect("Non-empty line string given");
+
+        assert_eq!(dist, ruler.line_distance_coords(coords));
+        assert_eq!(
+            ruler.along(line, dist * 0.5),
+            ruler.along_coords(coords, dist * 0.5)
+        );
+
+        let pol = ruler
+            .point_on_line(line, &POINTS[i])
+            .expect("Non-empty line string given");
+        let pol_coords = ruler
+            .point_on_line_coords(coords, &POINTS[i])
+            .expect("Non-empty line string given");
+        assert_eq!(pol.point(), pol_coords.point());
+        assert_eq!(pol.index(), pol_coords.index());
+        assert_eq!(pol.t(), pol_coords.t());
+
+        assert_eq!(
+            ruler.line_slice(&start, &stop, line),
+            ruler.line_slice_coords(&start, &stop, coords)
+        );
+        assert_eq!(
+            ruler.line_slice_along(dist * 0.3, dist * 0.7, line),
+            ruler.line_slice_along_coords(dist * 0.3, dist * 0.7, coords)
+        );
+    }
+}
+
+#[test]
+fn test_square_point_to_segment_distance() {
+    let ruler = fixtures::ruler_km();
+    let p = point!(x: -77.0, y: 38.0);
+    let p0 = point!(x: -77.001, y: 38.002);
+    let p1 = point!(x: -77.003, y: 38.001);
+
+    let d2 = ruler.square_point_to_segment_distance(&p, &p0, &p1);
+    assert_eq!(d2.sqrt(), ruler.point_to_segment_distance(&p, &p0, &p1));
+    assert_eq!(0.0, ruler.square_point_to_segment_distance(&p0, &p0, &p1));
+}
+
+#[test]
+fn test_nearest_segment() {
+    let ruler = fixtures::ruler_km();
+
+    for i in 0..LINES.len() {
+        let expected = ruler
+            .point_on_line(&LINES[i], &POINTS[i])
+            .expect("Non-empty line string given")
+            .index();
+        assert_eq!(
+            Some(expected),
+            ruler.nearest_segment(&LINES[i], &POINTS[i])
+        );
+    }
+
+    let single = line_string![(x: -77.0, y: 38.0)];
+    assert_eq!(None, ruler.nearest_segment(&single, &POINTS[0]));
+    assert_eq!(None, ruler.nearest_segment(&line_string![], &POINTS[0]));
+}
+
+#[test]
+fn test_point_on_line_range() {
+    let ruler = fixtures::ruler_km();
+    let line = line_string![
+        (x: -77.0, y: 38.0),
+        (x: -76.99, y: 38.0),
+        (x: -76.99, y: 38.01),
+        (x: -77.0, y: 38.01),
+    ];
+    // closest to the last segment, but also close to the first one
+    let point = point!(x: -76.995, y: 38.0095);
+
+    let full = ruler
+        .point_on_line(&line, &point)
+        .expect("Non-empty line string given");
+    assert_eq!(2, full.index());
+
+    let all = ruler
+        .point_on_line_range(&line, &point, 0, usize::MAX)
+        .expect("Non-empty line string given");
+    assert_eq!(full.point(), all.point());
+    assert_eq!(full.index(), all.index());
+
+    let first = ruler
+        .point_on_line_range(&line, &point, 0, 0)
+        .expect("Non-empty line string given");
+    let first_segment = line_string![(x: -77.0, y: 38.0), (x: -76.99, y: 38.0)];
+    assert_eq!(0, first.index());
+    assert_eq!(
+        ruler
+            .point_on_line(&first_segment, &point)
+            .expect("Non-empty line string given")
+            .point(),
+        first.point()
+    );
+
+    // range past the end of the line is clamped to the last segment
+    let clamped = ruler
+        .point_on_line_range(&line, &point, 5, 10)
+        .expect("Non-empty line string given");
+    assert_eq!(2, clamped.index());
+
+    assert!(ruler
+        .point_on_line_range(&line_string![], &point, 0, 1)
+        .is_none());
+}
+
+#[test]
+fn test_snap_within() {
+    let ruler = fixtures::ruler_km();
+
+    for i in 0..LINES.len() {
+        let pol = ruler
+            .point_on_line(&LINES[i], &POINTS[i])
+            .expect("Non-empty line string given");
+        let dist = ruler.distance(&pol.point(), &POINTS[i]);
+
+        let snapped = ruler
+            .snap_within(&LINES[i], &POINTS[i], dist * 1.001)
+            .expect("Point within the given distance");
+        assert_eq!(pol.point(), snapped.point());
+        assert_eq!(pol.index(), snapped.index());
+
+        if dist > 0.0 {
+            assert!(ruler
+                .snap_within(&LINES[i], &POINTS[i], dist * 0.999)
+                .is_none());
+        }
+    }
+
+    assert!(ruler
+        .snap_within(&line_string![], &POINTS[0], f64::INFINITY)
+        .is_none());
+}
+
+#[test]
+fn test_nearest_vertices() {
+    let ruler = fixtures::ruler_km();
+    let line = &LINES[0];
+    let point = &POINTS[0];
+
+    let mut expected = line
+        .points_iter()
+        .map(|p| ruler.distance(point, &p))
+        .enumerate()
+        .collect::<Vec<(usize, f64)>>();
+    expected.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+
+    let nearest = ruler.nearest_vertices(line, point, 3);
+    assert_eq!(3, nearest.len());
+    for (actual, expected) in nearest.iter().zip(expected.iter()) {
+        assert_eq!(expected.0, actual.0);
+        assert_eq_err!(expected.1, actual.1, 1e-12);
+    }
+
+    let all = ruler.nearest_vertices(line, point, 1000);
+    assert_eq!(line.num_coords(), all.len());
+    assert!(all.windows(2).all(|pair| pair[0].1 <= pair[1].1));
+
+    assert!(ruler.nearest_vertices(line, point, 0).is_empty());
+    assert!(ruler.nearest_vertices(&line_string![], point, 3).is_empty());
+
+    // vertices with NaN coordinates are skipped
+    let with_nan = line_string![
+        (x: 0.0, y: 0.0),
+        (x: 1.0, y: 1.0),
+        (x: f64::NAN, y: 0.0),
+        (x: 2.0, y: 2.0),
+    ];
+    let origin = point!(x: 0.0, y: 0.0);
+    let indices = |k: usize| {
+        ruler
+            .nearest_vertices(&with_nan, &origin, k)
+            .iter()
+            .map(|&(i, _)| i)
+            .collect::<Vec<usize>>()
+    };
+    assert_eq!(vec![0, 1], indices(2));
+    assert_eq!(vec![0, 1, 3], indices(4));
+}
+
+#[test]
+fn test_matcher() {
+    let ruler = fixtures::ruler_km();
+    // a line going back and forth between two parallel roads
+    let line = line_string![
+        (x: -96.9, y: 32.83),
+        (x: -96.88, y: 32.83),
+        (x: -96.88, y: 32.831),
+        (x: -96.9, y: 32.831),
+    ];
+    let mut matcher = Matcher::new(ruler.clone(), line.clone(), 1);
+    assert_eq!(None, matcher.last_index());
+
+    let first = matcher
+        .snap(&point!(x: -96.89, y: 32.8301))
+        .expect("Non-empty line string given");
+    assert_eq!(0, first.index());
+    assert_eq!(Some(0), matcher.last_index());
+
+    // closer to the return road, which is outside of the window
+    let point = point!(x: -96.899, y: 32.8306);
+    assert_eq!(
+        2,
+        ruler
+            .point_on_line(&line, &point)
+            .expect("Non-empty line string given")
+            .index()
+    );
+    let second = matcher.snap(&point).expect("Non-empty line string given");
+    assert_eq!(0, second.index());
+
+    matcher.reset();
+    assert_eq!(None, matcher.last_index());
+    let third = matcher.snap(&point).expect("Non-empty line string given");
+    assert_eq!(2, third.index());
+
+    let mut empty = Matcher::new(ruler, line_string![], 1);
+    assert!(empty.snap(&point).is_none());
+    assert_eq!(None, empty.last_index());
+}
+
+#[test]
+fn test_offset_from_line() {
+    let ruler = fixtures::ruler_km();
+    let origin = point!(x: -96.9, y: 32.8351);
+    let line = LineString::from(vec![
+        origin,
+        ruler.offset(&origin, 2.0, 0.0),
+        ruler.offset(&origin, 2.0, 2.0),
+    ]);
+    let assert_point = |expected: Point<f64>, actual: Option<Point<f64>>| {
+        let actual = actual.unwrap();
+        assert_eq_err!(expected.lng(), actual.lng(), 1e-9);
+        assert_eq_err!(expected.lat(), actual.lat(), 1e-9);
+    };
+
+    // heading east, right is south
+    let near = ruler.offset(&origin, 1.0, 0.3);
+    assert_point(
+        ruler.offset(&origin, 1.0, -0.5),
+        ruler.offset_from_line(&line, &near, 0.5),
+    );
+    assert_point(
+        ruler.offset(&origin, 1.0, 0.5),
+        ruler.offset_from_line(&line, &near, -0.5),
+    );
+    // heading north, right is east
+    let near = ruler.offset(&origin, 1.5, 1.0);
+    assert_point(
+        ruler.offset(&origin, 2.25, 1.0),
+        ruler.offset_from_line(&line, &near, 0.25),
+    );
+
+    let single = line_string![(x: -96.9, y: 32.8351)];
+    assert_eq!(None, ruler.offset_from_line(&single, &origin, 1.0));
+    assert_eq!(None, ruler.offset_from_line(&line_string![], &origin, 1.0));
+}
+
+#[test]
+fn test_point_on_line() {
+    let ruler = fixtures::ruler_km();
+
+    // not Turf comparison because pointOnLine is bugged https://github.com/Turfjs/turf/issues/344
+    let line = line_string![
+        (x: -77.031669, y: 38.878605),
+        (x: -77.029609, y: 38.881946),
+    ];
+    let point = point!(x: -77.034076, y: 38.882017);
+    let result = ruler
+        .point_on_line(&line, &point)
+        .expect("Non-empty line string given");
+
+    assert_eq_err!(-77.03052689033436, result.point().lng(), 1e-6);
+    assert_eq_err!(38.880457324462576, result.point().lat(), 1e-6);
+    assert_eq!(0, result.index()); // index
     assert_eq_err!(0.5544221677861756, result.t(), 1e-6); // t
+    assert_eq_err!(
+        ruler.distance(&point, &result.point()),
+        result.dist(),
+        1e-12
+    );
 
     assert_eq!(
         0.0,
@@ -247,6 +2309,78 @@ fn test_point_on_line() {
     );
 }
 
+#[test]
+fn test_snap_to_network() {
+    let ruler = fixtures::ruler_km();
+
+    let near = line_string![
+        (x: -77.031669, y: 38.878605),
+        (x: -77.029609, y: 38.881946),
+    ];
+    let far = line_string![
+        (x: -77.041669, y: 38.878605),
+        (x: -77.049609, y: 38.881946),
+    ];
+    let network = vec![
+        ("far", far),
+        ("degenerate", line_string![(x: -77.034076, y: 38.882017)]),
+        ("near", near.clone()),
+    ];
+    let point = point!(x: -77.034076, y: 38.882017);
+
+    let (label, pol) = ruler
+        .snap_to_network(&network, &point)
+        .expect("Non-empty network given");
+    let expected = ruler
+        .point_on_line(&near, &point)
+        .expect("Non-empty line string given");
+
+    assert_eq!("near", label);
+    assert_eq!(expected.point(), pol.point());
+    assert_eq!(expected.index(), pol.index());
+    assert_eq!(expected.t(), pol.t());
+
+    let empty: Vec<(u32, LineString<f64>)> = vec![];
+    assert!(ruler.snap_to_network(&empty, &point).is_none());
+}
+
+#[test]
+fn test_point_side() {
+    let ruler = fixtures::ruler_km();
+
+    let line = line_string![
+        (x: -77.031669, y: 38.878605),
+        (x: -77.029609, y: 38.881946),
+    ];
+    let left = point!(x: -77.034076, y: 38.882017);
+    let right = point!(x: -77.028, y: 38.879);
+    let on = point!(x: -77.031669, y: 38.878605);
+
+    let (pol, side) = ruler
+        .point_side(&line, &left)
+        .expect("Non-empty line string given");
+    let expected = ruler
+        .point_on_line(&line, &left)
+        .expect("Non-empty line string given");
+
+    assert_eq!(Side::Left, side);
+    assert_eq!(expected.point(), pol.point());
+    assert_eq!(expected.index(), pol.index());
+    assert_eq!(expected.t(), pol.t());
+
+    let (_, side) = ruler
+        .point_side(&line, &right)
+        .expect("Non-empty line string given");
+    assert_eq!(Side::Right, side);
+
+    let (_, side) = ruler
+        .point_side(&line, &on)
+        .expect("Non-empty line string given");
+    assert_eq!(Side::On, side);
+
+    assert!(ruler.point_side(&line_string![], &left).is_none());
+}
+
 #[test]
 fn test_point_to_segment_distance() {
     let ruler = fixtures::ruler_km();
@@ -260,6 +2394,92 @@ fn test_point_to_segment_distance() {
     assert_eq_err!(0.37461484020420416, distance, 1e-6);
 }
 
+#[test]
+fn test_closest_point_on_segment() {
+    let ruler = fixtures::ruler_km();
+
+    let p = point!(x: -77.034076, y: 38.882017);
+    let p0 = point!(x: -77.031669, y: 38.878605);
+    let p1 = point!(x: -77.029609, y: 38.881946);
+
+    let (closest, distance) = ruler.closest_point_on_segment(&p, &p0, &p1);
+    let line = line_string![p0.into(), p1.into()];
+    let expected = ruler
+        .point_on_line(&line, &p)
+        .expect("Non-empty line string given");
+
+    assert_eq!(ruler.point_to_segment_distance(&p, &p0, &p1), distance);
+    assert_eq_err!(expected.point().lng(), closest.lng(), 1e-12);
+    assert_eq_err!(expected.point().lat(), closest.lat(), 1e-12);
+
+    // beyond the ends of the segment
+    let (closest, _) =
+        ruler.closest_point_on_segment(&point!(x: -80.0, y: 38.0), &p0, &p1);
+    assert_eq!(p0, closest);
+    let (closest, _) =
+        ruler.closest_point_on_segment(&point!(x: -75.0, y: 38.0), &p0, &p1);
+    assert_eq!(p1, closest);
+}
+
+#[test]
+fn test_distance_between_on_line() {
+    let ruler = fixtures::ruler_km();
+
+    for i in 0..LINES.len() {
+        let line = &LINES[i];
+        let dist = ruler.line_distance(line);
+        let start = ruler
+            .along(line, dist * 0.3)
+            .expect("Non-empty line string given");
+        let stop = ruler
+            .along(line, dist * 0.7)
+            .expect("Non-empty line string given");
+        let a = ruler
+            .point_on_line(line, &start)
+            .expect("Non-empty line string given");
+        let b = ruler
+            .point_on_line(line, &stop)
+            .expect("Non-empty line string given");
+
+        let expected =
+            ruler.line_distance(&ruler.line_slice(&start, &stop, line));
+        let actual = ruler.distance_between_on_line(line, &a, &b);
+        assert_eq_err!(expected, actual, 1e-6);
+        assert_eq!(
+            ruler.distance_between_on_line(line, &a, &b),
+            ruler.distance_between_on_line(line, &b, &a)
+        );
+        assert_eq!(0.0, ruler.distance_between_on_line(line, &a, &a));
+    }
+}
+
+#[test]
+fn test_distance_to_point_along() {
+    let ruler = fixtures::ruler_km();
+
+    for i in 0..LINES.len() {
+        let line = &LINES[i];
+        let dist = ruler.line_distance(line);
+        let point = ruler
+            .along(line, dist * 0.4)
+            .expect("Non-empty line string given");
+        let actual = ruler
+            .distance_to_point_along(line, &point)
+            .expect("Non-empty line string given");
+        assert_eq_err!(dist * 0.4, actual, 1e-6);
+
+        let start = ruler
+            .distance_to_point_along(line, &line[0].into())
+            .expect("Non-empty line string given");
+        assert_eq!(0.0, start);
+    }
+
+    assert_eq!(
+        None,
+        ruler.distance_to_point_along(&line_string![], &POINTS[0])
+    );
+}
+
 #[test]
 fn test_line_slice() {
     let ruler = fixtures::ruler_km();
@@ -276,43 +2496,167 @@ fn test_line_slice() {
         let actual =
             ruler.line_distance(&ruler.line_slice(&start, &stop, &LINES[i]));
 
-        // @todo Should update turf_lineSlice and revert maxError back.
-        assert_eq_err!(expected, actual, 1e-4);
+        // @todo Should update turf_lineSlice and revert maxError back.
+        assert_eq_err!(expected, actual, 1e-4);
+    }
+}
+
+#[test]
+fn test_line_slice_along_empty() {
+    let ruler = fixtures::ruler_km();
+
+    let empty_line = line_string![];
+    let expected = ruler.line_distance(&empty_line);
+    let actual =
+        ruler.line_distance(&ruler.line_slice_along(0.0, 0.0, &empty_line));
+
+    assert_eq_err!(expected, actual, 0.0);
+}
+
+#[test]
+fn test_line_slice_along() {
+    let ruler = fixtures::ruler_km();
+
+    for i in 0..LINES.len() {
+        if i == 46 {
+            // skip due to Turf bug https://github.com/Turfjs/turf/issues/351
+            //continue;
+        }
+        let dist = ruler.line_distance(&LINES[i]);
+        let expected = turf::LINESLICE[i];
+        let actual = ruler.line_distance(&ruler.line_slice_along(
+            dist * 0.3,
+            dist * 0.7,
+            &LINES[i],
+        ));
+
+        // @todo Should update turf_lineSlice and revert maxError back.
+        assert_eq_err!(expected, actual, 1e-4);
+    }
+}
+
+#[test]
+fn test_line_slice_along_clamped() {
+    let ruler = fixtures::ruler_km();
+    let line = &LINES[0];
+    let dist = ruler.line_distance(line);
+
+    let (slice, start, stop) =
+        ruler.line_slice_along_clamped(dist * 0.3, dist * 0.7, line);
+    assert_eq!(ruler.line_slice_along(dist * 0.3, dist * 0.7, line), slice);
+    assert_eq!((dist * 0.3, dist * 0.7), (start, stop));
+
+    let (slice, start, stop) =
+        ruler.line_slice_along_clamped(-1.0, dist + 1.0, line);
+    assert_eq!((0.0, dist), (start, stop));
+    assert_eq_err!(dist, ruler.line_distance(&slice), 1e-12);
+
+    let (_, start, stop) =
+        ruler.line_slice_along_clamped(dist * 0.7, dist * 0.3, line);
+    assert_eq!((dist * 0.7, dist * 0.7), (start, stop));
+
+    let (slice, start, stop) =
+        ruler.line_slice_along_clamped(1.0, 2.0, &line_string![]);
+    assert_eq!((0.0, 0.0), (start, stop));
+    assert_eq!(0, slice.num_coords());
+}
+
+#[test]
+fn test_split_at_point() {
+    let ruler = fixtures::ruler_km();
+
+    for i in 0..LINES.len() {
+        let line = &LINES[i];
+        let (head, tail) = ruler.split_at_point(line, &POINTS[i]);
+        let pol = ruler
+            .point_on_line(line, &POINTS[i])
+            .expect("Non-empty line string given");
+
+        assert_eq!(Some(pol.point()), head.points_iter().next_back());
+        assert_eq!(Some(pol.point()), tail.points_iter().next());
+        assert_eq!(line[0], head[0]);
+        assert_eq!(line[line.num_coords() - 1], tail[tail.num_coords() - 1]);
+        assert_eq_err!(
+            ruler.line_distance(line),
+            ruler.line_distance(&head) + ruler.line_distance(&tail),
+            1e-9
+        );
     }
+
+    // splitting at a vertex doesn't repeat it
+    let line = line_string![
+        (x: -96.9, y: 32.83),
+        (x: -96.89, y: 32.84),
+        (x: -96.88, y: 32.83),
+    ];
+    let (head, tail) =
+        ruler.split_at_point(&line, &point!(x: -96.89, y: 32.85));
+    assert_eq!(
+        line_string![(x: -96.9, y: 32.83), (x: -96.89, y: 32.84)],
+        head
+    );
+    assert_eq!(
+        line_string![(x: -96.89, y: 32.84), (x: -96.88, y: 32.83)],
+        tail
+    );
+
+    let single = line_string![(x: -96.9, y: 32.83)];
+    assert_eq!(
+        (single.clone(), single.clone()),
+        ruler.split_at_point(&single, &POINTS[0])
+    );
 }
 
 #[test]
-fn test_line_slice_along_empty() {
+fn test_extend() {
     let ruler = fixtures::ruler_km();
+    let line = line_string![
+        (x: -96.9, y: 32.83),
+        (x: -96.89, y: 32.84),
+        (x: -96.89, y: 32.84),
+    ];
+    let bearing = ruler.bearing(&line[0].into(), &line[1].into());
 
-    let empty_line = line_string![];
-    let expected = ruler.line_distance(&empty_line);
-    let actual =
-        ruler.line_distance(&ruler.line_slice_along(0.0, 0.0, &empty_line));
+    let extended = ruler.extend(&line, 2.0);
+    assert_eq!(4, extended.num_coords());
+    assert_eq!(line.0[..], extended.0[..3]);
+    let end = extended[3].into();
+    assert_eq_err!(2.0, ruler.distance(&line[2].into(), &end), 1e-9);
+    assert_eq_err!(bearing, ruler.bearing(&line[2].into(), &end), 1e-9);
 
-    assert_eq_err!(expected, actual, 0.0);
+    assert_eq!(line, ruler.extend(&line, 0.0));
+    assert_eq!(line, ruler.extend(&line, -1.0));
+
+    let dot = line_string![(x: -96.9, y: 32.83), (x: -96.9, y: 32.83)];
+    assert_eq!(dot, ruler.extend(&dot, 1.0));
+    assert_eq!(line_string![], ruler.extend(&line_string![], 1.0));
 }
 
 #[test]
-fn test_line_slice_along() {
+fn test_trim() {
     let ruler = fixtures::ruler_km();
 
     for i in 0..LINES.len() {
-        if i == 46 {
-            // skip due to Turf bug https://github.com/Turfjs/turf/issues/351
-            //continue;
-        }
-        let dist = ruler.line_distance(&LINES[i]);
-        let expected = turf::LINESLICE[i];
-        let actual = ruler.line_distance(&ruler.line_slice_along(
-            dist * 0.3,
-            dist * 0.7,
-            &LINES[i],
-        ));
-
-        // @todo Should update turf_lineSlice and revert maxError back.
-        assert_eq_err!(expected, actual, 1e-4);
+        let line = &LINES[i];
+        let dist = ruler.line_distance(line);
+
+        let trimmed = ruler.trim(line, dist * 0.1, dist * 0.2);
+        assert_eq!(
+            ruler.line_slice_along(dist * 0.1, dist * 0.8, line),
+            trimmed
+        );
+        assert_eq_err!(dist * 0.7, ruler.line_distance(&trimmed), 1e-6);
+
+        assert_eq!(line_string![], ruler.trim(line, dist * 0.5, dist * 0.5));
+        assert_eq!(line_string![], ruler.trim(line, dist, 0.0));
     }
+
+    let line = &LINES[0];
+    assert_eq!(
+        ruler.trim(line, 0.0, 0.0),
+        ruler.trim(line, -1.0, -1.0)
+    );
+    assert_eq!(line_string![], ruler.trim(&line_string![], 0.0, 0.0));
 }
 
 #[test]
@@ -332,6 +2676,193 @@ fn test_line_slice_reverse() {
     assert_eq_err!(0.018676476689649835, actual, 1e-6);
 }
 
+#[test]
+fn test_chunk() {
+    let ruler = fixtures::ruler_km();
+
+    for i in 0..LINES.len() {
+        let total = ruler.line_distance(&LINES[i]);
+        if total == 0.0 {
+            assert!(ruler.chunk(&LINES[i], 0.01).is_empty());
+            continue;
+        }
+
+        let chunk_length = total / 3.5;
+        let chunks = ruler.chunk(&LINES[i], chunk_length);
+        let lengths = chunks
+            .iter()
+            .map(|chunk| ruler.line_distance(chunk))
+            .collect::<Vec<f64>>();
+
+        assert_eq!(4, chunks.len());
+        assert_eq_err!(total, lengths.iter().sum::<f64>(), 1e-9);
+        for j in 0..3 {
+            assert_eq_err!(chunk_length, lengths[j], 1e-9);
+            assert_eq!(
+                chunks[j][chunks[j].num_coords() - 1],
+                chunks[j + 1][0]
+            );
+        }
+        assert_eq_err!(chunk_length / 2.0, lengths[3], 1e-9);
+    }
+}
+
+#[test]
+#[should_panic(expected = "Chunk length must be positive")]
+fn test_chunk_zero_length() {
+    let ruler = fixtures::ruler_km();
+
+    ruler.chunk(&LINES[0], 0.0);
+}
+
+#[test]
+fn test_buffer_line() {
+    let ruler = fixtures::ruler_km();
+
+    let line = line_string![
+        (x: 30.50, y: 32.8351),
+        (x: 30.51, y: 32.8351),
+        (x: 30.51, y: 32.8351),
+        (x: 30.51, y: 32.8451),
+        (x: 30.50, y: 32.8451),
+    ];
+    let buffered = ruler.buffer_line(&line, 0.1);
+    let exterior = buffered.exterior();
+
+    assert_eq!(exterior[0], exterior[exterior.num_coords() - 1]);
+    for p in exterior.points_iter() {
+        let (_, d) = (0..line.num_coords() - 1)
+            .map(|i| {
+                ruler.closest_point_on_segment(
+                    &p,
+                    &line[i].into(),
+                    &line[i + 1].into(),
+                )
+            })
+            .fold((p, f64::INFINITY), |a, b| if b.1 < a.1 { b } else { a });
+
+        // mitered corners lie further than the buffer distance
+        assert!(d > 0.1 - 1e-6 && d < 0.1 * 2.0_f64.sqrt() + 1e-6);
+    }
+    let cap = ruler.distance(&exterior[0].into(), &line[0].into());
+    assert_eq_err!(0.1, cap, 1e-6);
+
+    let length = ruler.line_distance(&line);
+    assert_eq_err!(length * 0.2, ruler.area(&buffered), 0.01);
+
+    let empty = ruler.buffer_line(&line_string![(x: 30.5, y: 32.8)], 0.1);
+    assert_eq!(0, empty.exterior().num_coords());
+}
+
+#[test]
+fn test_buffer_line_hairpin() {
+    let ruler = fixtures::ruler_km();
+    let origin = point!(x: 30.5, y: 32.8351);
+    let at = |dx: f64, dy: f64| ruler.offset(&origin, dx, dy);
+    let line = LineString::from(vec![
+        at(0.0, 0.0),
+        at(1.0, 0.0),
+        at(1.0, 0.2),
+        at(0.0, 0.2),
+    ]);
+
+    // the legs are closer than twice the buffer distance, so the ring
+    // crosses itself
+    let buffered = ruler.buffer_line(&line, 0.5);
+    let pieces = ruler.split_at_self_intersections(buffered.exterior());
+    assert!(pieces.len() > 1);
+
+    // the distance to the line still tells which points are within it
+    let within = |p: &Point<f64>| {
+        ruler.point_on_line(&line, p).unwrap().dist() <= 0.5
+    };
+    assert!(within(&at(0.25, 0.0)));
+    assert!(within(&at(1.0, 0.1)));
+    assert!(within(&at(1.3, 0.5)));
+    assert!(!within(&at(0.5, 0.8)));
+    assert!(!within(&at(-0.6, 0.1)));
+}
+
+#[test]
+fn test_polygon_bbox() {
+    let ruler = fixtures::ruler_km();
+    let polygon = Polygon::new(
+        line_string![
+            (x: -67.031, y: 50.458),
+            (x: -67.031, y: 50.534),
+            (x: -66.929, y: 50.534),
+            (x: -66.929, y: 50.458),
+            (x: -67.031, y: 50.458),
+        ],
+        vec![],
+    );
+    let bbox = ruler.polygon_bbox(&polygon).unwrap();
+    assert_eq!(bbox.min(), Coordinate { x: -67.031, y: 50.458 });
+    assert_eq!(bbox.max(), Coordinate { x: -66.929, y: 50.534 });
+
+    let empty = Polygon::new(line_string![], vec![]);
+    assert_eq!(None, ruler.polygon_bbox(&empty));
+
+    let other = Polygon::new(
+        line_string![
+            (x: -66.0, y: 50.0),
+            (x: -65.0, y: 51.0),
+            (x: -66.0, y: 51.0),
+            (x: -66.0, y: 50.0),
+        ],
+        vec![],
+    );
+    let bbox = ruler
+        .multipolygon_bbox(&MultiPolygon(vec![empty.clone(), polygon, other]))
+        .unwrap();
+    assert_eq!(bbox.min(), Coordinate { x: -67.031, y: 50.0 });
+    assert_eq!(bbox.max(), Coordinate { x: -65.0, y: 51.0 });
+    assert_eq!(None, ruler.multipolygon_bbox(&MultiPolygon(vec![empty])));
+}
+
+#[test]
+fn test_rect_to_polygon_area() {
+    let ruler = fixtures::ruler_km();
+    let bbox = ruler.buffer_point(&point!(x: -96.9, y: 32.8351), 1.0);
+
+    assert_eq_err!(4.0, ruler.area(&bbox.to_polygon()), 1e-12);
+}
+
+#[test]
+fn test_line_intersections() {
+    let ruler = fixtures::ruler_km();
+    let a = line_string![
+        (x: -96.9, y: 32.83),
+        (x: -96.89, y: 32.84),
+        (x: -96.88, y: 32.83),
+    ];
+    let b = line_string![
+        (x: -96.9, y: 32.835),
+        (x: -96.88, y: 32.835),
+    ];
+
+    let points = ruler.line_intersections(&a, &b);
+    assert_eq!(2, points.len());
+    assert_eq_err!(-96.895, points[0].lng(), 1e-12);
+    assert_eq_err!(32.835, points[0].lat(), 1e-12);
+    assert_eq_err!(-96.885, points[1].lng(), 1e-12);
+    assert_eq_err!(32.835, points[1].lat(), 1e-12);
+
+    // crossing at the shared vertex of two segments is only reported once
+    let vertical = line_string![
+        (x: -96.89, y: 32.835),
+        (x: -96.89, y: 32.845),
+    ];
+    let points = ruler.line_intersections(&a, &vertical);
+    assert_eq!(1, points.len());
+    assert_eq_err!(-96.89, points[0].lng(), 1e-12);
+    assert_eq_err!(32.84, points[0].lat(), 1e-12);
+
+    let far = line_string![(x: -97.0, y: 33.0), (x: -97.1, y: 33.1)];
+    assert!(ruler.line_intersections(&a, &far).is_empty());
+    assert!(ruler.line_intersections(&line_string![], &b).is_empty());
+}
+
 #[test]
 fn test_buffer_point() {
     let ruler_miles = fixtures::ruler_miles();
@@ -363,6 +2894,126 @@ fn test_buffer_bbox() {
     assert_eq_err!(39.00901728774289, bbox2.max().y, 1e-6);
 }
 
+#[test]
+fn test_buffer_bbox_negative() {
+    let ruler = fixtures::ruler_km();
+
+    let bbox = Rect::new(
+        Coordinate { x: 30.0, y: 38.0 },
+        Coordinate { x: 40.0, y: 39.0 },
+    );
+    let bbox2 = ruler.buffer_bbox(&ruler.buffer_bbox(&bbox, 1.0), -1.0);
+
+    assert_eq_err!(bbox.min().x, bbox2.min().x, 1e-12);
+    assert_eq_err!(bbox.min().y, bbox2.min().y, 1e-12);
+    assert_eq_err!(bbox.max().x, bbox2.max().x, 1e-12);
+    assert_eq_err!(bbox.max().y, bbox2.max().y, 1e-12);
+}
+
+#[test]
+fn test_buffer_bbox_collapse() {
+    let ruler = fixtures::ruler_km();
+
+    let bbox = Rect::new(
+        Coordinate { x: 30.0, y: 38.0 },
+        Coordinate { x: 30.01, y: 38.01 },
+    );
+    let collapsed = ruler.buffer_bbox(&bbox, -10.0);
+
+    assert_eq!(collapsed.min(), collapsed.max());
+    assert_eq_err!(30.005, collapsed.min().x, 1e-12);
+    assert_eq_err!(38.005, collapsed.min().y, 1e-12);
+
+    // only the narrower dimension collapses
+    let wide = Rect::new(
+        Coordinate { x: 30.0, y: 38.0 },
+        Coordinate { x: 31.0, y: 38.01 },
+    );
+    let contracted = ruler.buffer_bbox(&wide, -1.0);
+
+    assert!(contracted.min().x > 30.0 && contracted.max().x < 31.0);
+    assert!(contracted.min().x < contracted.max().x);
+    assert_eq_err!(38.005, contracted.min().y, 1e-12);
+    assert_eq_err!(38.005, contracted.max().y, 1e-12);
+}
+
+#[test]
+fn test_buffer_bbox_wide() {
+    let ruler = fixtures::ruler_km();
+    let h = 1.0 / multipliers_for(32.8351, DistanceUnit::Kilometers).0;
+
+    let wide = Rect::new(
+        Coordinate { x: -170.0, y: 38.0 },
+        Coordinate { x: 170.0, y: 39.0 },
+    );
+    let grown = ruler.buffer_bbox(&wide, 1.0);
+    assert_eq_err!(-170.0 - h, grown.min().x, 1e-12);
+    assert_eq_err!(170.0 + h, grown.max().x, 1e-12);
+
+    let world = Rect::new(
+        Coordinate { x: -180.0, y: -80.0 },
+        Coordinate { x: 180.0, y: 80.0 },
+    );
+    let contracted = ruler.buffer_bbox(&world, -1.0);
+    assert_eq_err!(-180.0 + h, contracted.min().x, 1e-12);
+    assert_eq_err!(180.0 - h, contracted.max().x, 1e-12);
+}
+
+#[test]
+fn test_buffer_bbox_collapse_over_dateline() {
+    let ruler = fixtures::ruler_km();
+
+    let bbox = Rect::new(
+        Coordinate { x: 179.9, y: 32.7 },
+        Coordinate { x: -179.9, y: 32.9 },
+    );
+    let collapsed = ruler.buffer_bbox(&bbox, -100.0);
+
+    assert_eq_err!(180.0, collapsed.min().x, 1e-12);
+    assert_eq_err!(180.0, collapsed.max().x, 1e-12);
+
+    // the center of a box crossing the date line is wrapped back into range
+    let bbox = Rect::new(
+        Coordinate { x: 178.0, y: 30.0 },
+        Coordinate { x: -170.0, y: 30.1 },
+    );
+    let collapsed = ruler.buffer_bbox(&bbox, -2000.0);
+
+    assert_eq_err!(-176.0, collapsed.min().x, 1e-12);
+    assert_eq_err!(-176.0, collapsed.max().x, 1e-12);
+}
+
+#[test]
+fn test_buffer_bbox_xy() {
+    let ruler = fixtures::ruler_km();
+
+    let bbox = Rect::new(
+        Coordinate { x: 30.0, y: 38.0 },
+        Coordinate { x: 40.0, y: 39.0 },
+    );
+
+    assert_eq!(
+        ruler.buffer_bbox(&bbox, 1.0),
+        ruler.buffer_bbox_xy(&bbox, 1.0, 1.0)
+    );
+
+    let bbox2 = ruler.buffer_bbox_xy(&bbox, 2.0, 0.5);
+    let expected_h = ruler.buffer_bbox(&bbox, 2.0);
+    let expected_v = ruler.buffer_bbox(&bbox, 0.5);
+
+    assert_eq_err!(expected_h.min().x, bbox2.min().x, 1e-12);
+    assert_eq_err!(expected_h.max().x, bbox2.max().x, 1e-12);
+    assert_eq_err!(expected_v.min().y, bbox2.min().y, 1e-12);
+    assert_eq_err!(expected_v.max().y, bbox2.max().y, 1e-12);
+
+    // only the contracted dimension collapses
+    let collapsed = ruler.buffer_bbox_xy(&bbox, 1.0, -100.0);
+
+    assert_eq_err!(38.5, collapsed.min().y, 1e-12);
+    assert_eq_err!(38.5, collapsed.max().y, 1e-12);
+    assert!(collapsed.min().x < 30.0 && collapsed.max().x > 40.0);
+}
+
 #[test]
 fn test_inside_bbox() {
     let ruler = fixtures::ruler_km();
@@ -388,6 +3039,91 @@ fn test_inside_bbox_over_dateline() {
     assert!(ruler.inside_bbox(&point!(x: 180.0, y: 32.8), &bbox));
 }
 
+#[test]
+fn test_clamp_to_bbox() {
+    let ruler = fixtures::ruler_km();
+
+    let bbox = Rect::new(
+        Coordinate { x: 30.0, y: 38.0 },
+        Coordinate { x: 40.0, y: 39.0 },
+    );
+
+    let inside = point!(x: 35.0, y: 38.5);
+    assert_eq!(inside, ruler.clamp_to_bbox(&inside, &bbox));
+    assert_eq!(
+        point!(x: 40.0, y: 39.0),
+        ruler.clamp_to_bbox(&point!(x: 45.0, y: 45.0), &bbox)
+    );
+    assert_eq!(
+        point!(x: 30.0, y: 38.5),
+        ruler.clamp_to_bbox(&point!(x: 20.0, y: 38.5), &bbox)
+    );
+    assert_eq!(
+        point!(x: 35.0, y: 38.0),
+        ruler.clamp_to_bbox(&point!(x: 35.0, y: 30.0), &bbox)
+    );
+}
+
+#[test]
+fn test_clamp_to_bbox_over_dateline() {
+    let ruler = fixtures::ruler_km();
+
+    let bbox = Rect::new(
+        Coordinate { x: 179.9, y: 32.7 },
+        Coordinate { x: -179.9, y: 32.9 },
+    );
+
+    let inside = point!(x: 180.0, y: 32.8);
+    assert_eq!(inside, ruler.clamp_to_bbox(&inside, &bbox));
+    assert_eq!(
+        point!(x: 179.9, y: 32.8),
+        ruler.clamp_to_bbox(&point!(x: 179.0, y: 32.8), &bbox)
+    );
+    assert_eq!(
+        point!(x: -179.9, y: 32.9),
+        ruler.clamp_to_bbox(&point!(x: -179.0, y: 33.0), &bbox)
+    );
+}
+
+#[test]
+fn test_distance_to_bbox() {
+    let ruler = fixtures::ruler_km();
+
+    let bbox = Rect::new(
+        Coordinate { x: 30.0, y: 38.0 },
+        Coordinate { x: 40.0, y: 39.0 },
+    );
+
+    let inside = point!(x: 35.0, y: 38.5);
+    let on_edge = point!(x: 40.0, y: 38.5);
+    let outside_edge = point!(x: 35.0, y: 39.5);
+    let outside_corner = point!(x: 41.0, y: 39.5);
+
+    assert_eq!(0.0, ruler.distance_to_bbox(&inside, &bbox));
+    assert_eq!(0.0, ruler.distance_to_bbox(&on_edge, &bbox));
+    assert_eq_err!(
+        ruler.distance(&outside_edge, &point!(x: 35.0, y: 39.0)),
+        ruler.distance_to_bbox(&outside_edge, &bbox),
+        1e-12
+    );
+    assert_eq_err!(
+        ruler.distance(&outside_corner, &point!(x: 40.0, y: 39.0)),
+        ruler.distance_to_bbox(&outside_corner, &bbox),
+        1e-12
+    );
+
+    let over_dateline = Rect::new(
+        Coordinate { x: 179.9, y: 32.7 },
+        Coordinate { x: -179.9, y: 32.9 },
+    );
+    let east = point!(x: -179.8, y: 32.8);
+    assert_eq_err!(
+        ruler.distance(&east, &point!(x: -179.9, y: 32.8)),
+        ruler.distance_to_bbox(&east, &over_dateline),
+        1e-12
+    );
+}
+
 #[test]
 fn test_from_tile() {
     let ruler = CheapRuler::new(50.5, DistanceUnit::Kilometers);
@@ -402,3 +3138,200 @@ fn test_from_tile() {
         2e-5
     );
 }
+
+#[test]
+fn test_contains_point() {
+    let ruler = fixtures::ruler_km();
+
+    let polygon = Polygon::new(
+        line_string![
+            (x: 30.0, y: 38.0),
+            (x: 40.0, y: 38.0),
+            (x: 40.0, y: 39.0),
+            (x: 30.0, y: 39.0),
+            (x: 30.0, y: 38.0),
+        ],
+        vec![line_string![
+            (x: 32.0, y: 38.2),
+            (x: 34.0, y: 38.2),
+            (x: 34.0, y: 38.8),
+            (x: 32.0, y: 38.8),
+            (x: 32.0, y: 38.2),
+        ]],
+    );
+
+    assert!(ruler.contains_point(&polygon, &point!(x: 35.0, y: 38.5)));
+    assert!(!ruler.contains_point(&polygon, &point!(x: 33.0, y: 38.5)));
+    assert!(!ruler.contains_point(&polygon, &point!(x: 45.0, y: 38.5)));
+    assert!(!ruler.contains_point(&polygon, &point!(x: 35.0, y: 37.5)));
+}
+
+#[test]
+fn test_closest_on_polygon() {
+    let ruler = fixtures::ruler_km();
+    let polygon = Polygon::new(
+        line_string![
+            (x: 30.0, y: 38.0),
+            (x: 40.0, y: 38.0),
+            (x: 40.0, y: 39.0),
+            (x: 30.0, y: 39.0),
+            (x: 30.0, y: 38.0),
+        ],
+        vec![line_string![
+            (x: 32.0, y: 38.2),
+            (x: 34.0, y: 38.2),
+            (x: 34.0, y: 38.8),
+            (x: 32.0, y: 38.8),
+            (x: 32.0, y: 38.2),
+        ]],
+    );
+
+    let outside = point!(x: 35.0, y: 37.5);
+    let (closest, dist) = ruler.closest_on_polygon(&polygon, &outside).unwrap();
+    assert_eq_err!(35.0, closest.lng(), 1e-12);
+    assert_eq_err!(38.0, closest.lat(), 1e-12);
+    assert_eq_err!(ruler.distance(&outside, &closest), dist, 1e-12);
+
+    // inside, closest to the hole
+    let inside = point!(x: 34.1, y: 38.5);
+    let (closest, _) = ruler.closest_on_polygon(&polygon, &inside).unwrap();
+    assert_eq_err!(34.0, closest.lng(), 1e-12);
+    assert_eq_err!(38.5, closest.lat(), 1e-12);
+
+    let empty = Polygon::new(line_string![], vec![]);
+    assert_eq!(None, ruler.closest_on_polygon(&empty, &outside));
+}
+
+#[test]
+fn test_contains_points() {
+    let ruler = fixtures::ruler_km();
+
+    let polygon = Polygon::new(
+        line_string![
+            (x: 30.0, y: 38.0),
+            (x: 40.0, y: 38.0),
+            (x: 40.0, y: 39.0),
+            (x: 30.0, y: 39.0),
+            (x: 30.0, y: 38.0),
+        ],
+        vec![line_string![
+            (x: 32.0, y: 38.2),
+            (x: 34.0, y: 38.2),
+            (x: 34.0, y: 38.8),
+            (x: 32.0, y: 38.8),
+            (x: 32.0, y: 38.2),
+        ]],
+    );
+    let points = (0..200)
+        .map(|i| point!(x: 29.0 + i as f64 * 0.06, y: 37.9 + i as f64 * 0.006))
+        .collect::<Vec<_>>();
+
+    let mut out = vec![false; points.len()];
+    ruler.contains_points(&polygon, &points, &mut out);
+    for (p, inside) in points.iter().zip(out.iter()) {
+        assert_eq!(ruler.contains_point(&polygon, p), *inside);
+    }
+    assert!(out.iter().any(|&inside| inside));
+    assert!(out.iter().any(|&inside| !inside));
+
+    let empty = Polygon::new(line_string![], vec![]);
+    let mut out = vec![true; points.len()];
+    ruler.contains_points(&empty, &points, &mut out);
+    assert!(out.iter().all(|&inside| !inside));
+}
+
+#[test]
+#[should_panic(expected = "One result per point expected")]
+fn test_contains_points_short_output() {
+    let ruler = fixtures::ruler_km();
+    let polygon = Polygon::new(line_string![], vec![]);
+
+    ruler.contains_points(&polygon, &POINTS[..2], &mut [false]);
+}
+
+#[test]
+fn test_contains_point_over_dateline() {
+    let ruler = fixtures::ruler_km();
+
+    let polygon = Polygon::new(
+        line_string![
+            (x: 179.9, y: 32.7),
+            (x: -179.9, y: 32.7),
+            (x: -179.9, y: 32.9),
+            (x: 179.9, y: 32.9),
+            (x: 179.9, y: 32.7),
+        ],
+        vec![],
+    );
+
+    assert!(ruler.contains_point(&polygon, &point!(x: 180.0, y: 32.8)));
+    assert!(ruler.contains_point(&polygon, &point!(x: -179.95, y: 32.8)));
+    assert!(!ruler.contains_point(&polygon, &point!(x: 179.0, y: 32.8)));
+}
+
+#[test]
+fn test_bbox_in_polygon() {
+    let ruler = fixtures::ruler_km();
+
+    let polygon = Polygon::new(
+        line_string![
+            (x: 30.0, y: 38.0),
+            (x: 40.0, y: 38.0),
+            (x: 40.0, y: 39.0),
+            (x: 35.0, y: 38.6),
+            (x: 30.0, y: 39.0),
+            (x: 30.0, y: 38.0),
+        ],
+        vec![line_string![
+            (x: 31.0, y: 38.2),
+            (x: 32.0, y: 38.2),
+            (x: 32.0, y: 38.3),
+            (x: 31.0, y: 38.3),
+            (x: 31.0, y: 38.2),
+        ]],
+    );
+    let inside = Rect::new(
+        Coordinate { x: 36.0, y: 38.1 },
+        Coordinate { x: 38.0, y: 38.4 },
+    );
+    // straddles the exterior's edge
+    let straddling = Rect::new(
+        Coordinate { x: 36.0, y: 38.5 },
+        Coordinate { x: 38.0, y: 39.5 },
+    );
+    // all corners inside, but the notch in the exterior cuts into it
+    let notched = Rect::new(
+        Coordinate { x: 34.0, y: 38.1 },
+        Coordinate { x: 36.0, y: 38.65 },
+    );
+    // surrounds the polygon's hole
+    let around_hole = Rect::new(
+        Coordinate { x: 30.5, y: 38.1 },
+        Coordinate { x: 32.5, y: 38.4 },
+    );
+
+    assert!(ruler.bbox_in_polygon(&inside, &polygon));
+    assert!(!ruler.bbox_in_polygon(&straddling, &polygon));
+    assert!(!ruler.bbox_in_polygon(&notched, &polygon));
+    assert!(!ruler.bbox_in_polygon(&around_hole, &polygon));
+}
+
+#[test]
+fn test_local_frame() {
+    let ruler = fixtures::ruler_km();
+    let frame = ruler.local_frame(&POINTS[0]);
+    assert_eq!(POINTS[0], frame.origin());
+    assert_eq!((0.0, 0.0), frame.project(&POINTS[0]));
+
+    for i in 0..POINTS.len() - 1 {
+        let (a, b) = (&POINTS[i], &POINTS[i + 1]);
+        let (pa, pb) = (frame.project(a), frame.project(b));
+
+        assert_eq_err!(ruler.distance(a, b), frame.distance(pa, pb), 1e-9);
+        assert_eq_err!(ruler.bearing(a, b), frame.bearing(pa, pb), 1e-9);
+
+        let back = frame.unproject(pa);
+        assert_eq_err!(a.lng(), back.lng(), 1e-12);
+        assert_eq_err!(a.lat(), back.lat(), 1e-12);
+    }
+}